@@ -1,20 +1,40 @@
 from fly_stick._core import (
     PyDevicePool,
+    PyStateStream,
+    PyActivityStream,
     PyJoystick,
+    PyVirtualJoystick,
+    PyKeyMapper,
+    PyComboMatcher,
     JoystickInfo,
     JoystickState,
+    HatDirection,
     fetch_connected_joysticks,
+    fetch_connected_joysticks_detailed,
     DeviceItem,
     DeviceDescription,
+    DeviceReport,
+    InputEvent,
+    InputKind,
 )
 
 __all__ = [
     "PyDevicePool",
+    "PyStateStream",
+    "PyActivityStream",
     "PyJoystick",
+    "PyVirtualJoystick",
+    "PyKeyMapper",
+    "PyComboMatcher",
     "JoystickInfo",
     "JoystickState",
+    "HatDirection",
     "fetch_connected_joysticks",
+    "fetch_connected_joysticks_detailed",
     "DeviceItem",
     "DeviceDescription",
+    "DeviceReport",
+    "InputEvent",
+    "InputKind",
     "PyDevicePool",
 ]
@@ -1,42 +1,174 @@
-from typing import Optional
+import os
+from typing import Callable, Optional
+
+class HatDirection:
+    """Combined 8-way direction of a hat switch"""
+
+    Center: HatDirection
+    Up: HatDirection
+    UpRight: HatDirection
+    Right: HatDirection
+    DownRight: HatDirection
+    Down: HatDirection
+    DownLeft: HatDirection
+    Left: HatDirection
+    UpLeft: HatDirection
 
 class JoystickState:
     """Complete joystick state containing axes, buttons, and hats"""
 
     axes: dict[int, float]
     buttons: dict[int, int]
+    """Button triggers, set by a press and cleared by DevicePool after every fetch"""
+    held: dict[int, int]
+    """Current button levels, only changed by a reported press or release"""
     hats: dict[int, int]
+    raw_axes: dict[int, int]
+    """Unnormalized axis values as reported by evdev, keyed by physical axis code"""
+    button_hold_ms: dict[int, int]
+    """Milliseconds each button has been held, 0 when released"""
+    pressed_this_cycle: dict[int, bool]
+    """Buttons that went from released to pressed since the last fetch (`"pressed"` in to_dict)"""
+    rel_axes: dict[int, int]
+    """Accumulated deltas of relative axes (EV_REL), keyed by relative axis code"""
+    axis_crossings: dict[int, int]
+    """Direction each track_crossings axis last crossed 0.0 since the last fetch: 1, -1 or 0"""
+    timestamp_us: int
+    """Monotonic microseconds of the last update by a monitor, 0 if never updated. Ignored by ==."""
+    event_time_us: dict[int, int]
+    """Kernel timestamp (microseconds since the Unix epoch) of the latest event of each axis,
+    button and hat, keyed by physical code. Only filled for descriptions with event_times. Ignored by ==."""
 
     def __init__(self) -> None: ...
     def __eq__(self, value: object) -> bool: ...
-    def to_dict(self) -> dict[str, dict[int, float | int]]: ...
+    def to_dict(self) -> dict[str, dict[int, float | int | bool | HatDirection]]:
+        """Inputs keyed by kind; `"directions"` maps each hat's X axis code to its HatDirection"""
+        ...
+    def hat_direction(self, base: int) -> tuple[int, int] | None:
+        """Combined (x, y) direction of the hat whose X axis code is `base`.
+
+        The Y axis code is `base + 1` (e.g. ABS_HAT0X = 16, ABS_HAT0Y = 17).
+        x is -1 left / 1 right, y is -1 up / 1 down, a missing axis reads as 0.
+        Returns None if neither axis of the hat is present.
+        """
+        ...
+    def hat_as_direction(self, base: int) -> HatDirection:
+        """Combined 8-way direction of the hat whose X axis code is `base`, Center if missing"""
+        ...
+    def rescale_axis(self, code: int, out_min: float, out_max: float) -> float | None:
+        """Axis `code` remapped from [-1, 1] into [out_min, out_max], None if it is missing.
+
+        -1 maps to out_min, 0 to the midpoint and 1 to out_max, e.g.
+        state.rescale_axis(0, 1000.0, 2000.0) for servo pulse widths.
+        """
+        ...
+    def diff(self, other: JoystickState) -> JoystickState:
+        """Axes, buttons, held buttons, hats and relative axes that changed from this state to `other`.
+
+        Values are taken from `other`. Entries missing from `other` are reported as 0,
+        so the result is empty exactly when both states are equal.
+        """
+        ...
 
 class JoystickInfo:
     """Joystick information containing path and name"""
 
     path: str
     name: str
+    num_axes: int
+    """Number of analog axes, hats excluded (0 unless fetched with details)"""
+    num_buttons: int
+    """Number of buttons (0 unless fetched with details)"""
+    has_hats: bool
+    """Whether the device has hat switches (False unless fetched with details)"""
+    has_ff: bool
+    """Whether the device supports force feedback (False unless fetched with details)"""
+    uniq: Optional[str]
+    """Unique ID reported by the device (often a serial number), None if it has none"""
+    phys: Optional[str]
+    """Physical path of the device (e.g. the USB port), None if it has none"""
+    vendor_id: int
+    """USB (or bus) vendor ID of the device"""
+    product_id: int
+    """USB (or bus) product ID of the device"""
 
     def __init__(self, path: str, name: str) -> None: ...
 
-def fetch_connected_joysticks() -> list[JoystickInfo]:
+def fetch_connected_joysticks(include_all: bool = False) -> list[JoystickInfo]:
     """
     Fetch connected game controller devices
     This function retrieves a list of currently connected game controller devices.
     Each device is represented as a tuple containing the device path and device name.
+    Devices without joystick/gamepad buttons or absolute axes (keyboards, mice, ...)
+    are skipped unless `include_all` is True.
 
     Returns:
         Device list, each element is a tuple of (device_path, device_name)
     """
     ...
 
+def fetch_connected_joysticks_detailed(include_all: bool = False) -> list[JoystickInfo]:
+    """Fetch connected devices together with their capabilities.
+
+    Like fetch_connected_joysticks, but num_axes, num_buttons, has_hats and has_ff
+    are filled in from the evdev capability query, without opening the devices.
+    """
+    ...
+
 class DeviceItem:
-    """Device item with code and optional alias"""
+    """Device item with code and optional alias
+
+    Attributes:
+        code (int): evdev code of the axis, button or hat
+        alias (Optional[str]): Human-readable name of the item
+        invert (bool): Negate the normalized value of an axis (default: False)
+        debounce_ms (Optional[int]): Debounce time of a button or hat, overriding the pool default
+        smoothing (Optional[float]): Moving average factor of an axis in (0, 1), lower is smoother
+        button_code (Optional[int]): Synthetic button pressed while the axis is at or above `button_threshold`
+        button_threshold (Optional[float]): Normalized threshold of `button_code`, 0.5 if None
+        calib_min (Optional[int]): Raw reading at the physical minimum of an axis, maps to -1.0
+        calib_center (Optional[int]): Raw reading at the physical centre of an axis, maps to 0.0
+        calib_max (Optional[int]): Raw reading at the physical maximum of an axis, maps to 1.0
+        track_crossings (bool): Record in JoystickState.axis_crossings when the axis crosses 0.0
+        curve (str): Response curve of an axis, "linear", "quadratic" or "power:N", applied to
+            the normalized magnitude with its sign kept (default: "linear")
+        initial (Optional[float]): Value an axis reports before its first event, 0.0 if None
+        axis (Optional[str]): Axis of a hat the item stands for, "x" or "y"; `code` may then be
+            either code of the hat and is resolved when the description is built
+    """
 
     code: int
     alias: Optional[str]
+    invert: bool
+    debounce_ms: Optional[int]
+    smoothing: Optional[float]
+    button_code: Optional[int]
+    button_threshold: Optional[float]
+    calib_min: Optional[int]
+    calib_center: Optional[int]
+    calib_max: Optional[int]
+    track_crossings: bool
+    curve: str
+    initial: Optional[float]
+    axis: Optional[str]
 
-    def __init__(self, code: int, alias: Optional[str] = None) -> None: ...
+    def __init__(
+        self,
+        code: int,
+        alias: Optional[str] = None,
+        invert: bool = False,
+        debounce_ms: Optional[int] = None,
+        smoothing: Optional[float] = None,
+        button_code: Optional[int] = None,
+        button_threshold: Optional[float] = None,
+        calib_min: Optional[int] = None,
+        calib_center: Optional[int] = None,
+        calib_max: Optional[int] = None,
+        track_crossings: bool = False,
+        curve: str = "linear",
+        initial: Optional[float] = None,
+        axis: Optional[str] = None,
+    ) -> None: ...
 
 class DeviceDescription:
     """Device description containing metadata and input items.
@@ -53,6 +185,20 @@ class DeviceDescription:
         axes (list[DeviceItem]): List of analog axes available on the device
         buttons (list[DeviceItem]): List of buttons available on the device
         hats (list[DeviceItem]): List of hat/POV switches available on the device
+        rotation (int): Clockwise mounting rotation applied to X/Y (0, 90, 180 or 270)
+        members (list[str]): Physical device names of a merged description, empty otherwise
+        normalize_range (tuple[float, float]): Range analog axes are normalized to, (-1.0, 1.0) by default
+        raw_hats (bool): Keep hat values as reported by evdev instead of clamping them to -1/0/1
+        uniq (Optional[str]): Unique ID the device must report, matching it regardless of its name
+        phys (Optional[str]): Physical path the device must report, matching it regardless of its name
+        vendor_id (Optional[int]): USB vendor ID matching the device regardless of its name
+        product_id (Optional[int]): USB product ID matching the device regardless of its name
+        ignore (list[int]): Axis, button and hat codes whose events the device pool drops
+        grab (bool): Grab the device exclusively while it is open, hiding its events from other applications
+        match_mode (str): How device_name is matched against connected devices: "exact",
+            "trimmed" (default, ignores surrounding whitespace), "case_insensitive" or
+            "substring" (device name contains device_name, ignoring case)
+        event_times (bool): Keep the kernel timestamp of every input event in JoystickState.event_time_us
 
     Example:
         >>> device = DeviceDescription(
@@ -75,6 +221,18 @@ class DeviceDescription:
     axes: list[DeviceItem]
     buttons: list[DeviceItem]
     hats: list[DeviceItem]
+    rotation: int
+    members: list[str]
+    normalize_range: tuple[float, float]
+    raw_hats: bool
+    uniq: Optional[str]
+    phys: Optional[str]
+    vendor_id: Optional[int]
+    product_id: Optional[int]
+    ignore: list[int]
+    grab: bool
+    match_mode: str
+    event_times: bool
 
     def __init__(
         self,
@@ -85,30 +243,199 @@ class DeviceDescription:
         axes: Optional[list[DeviceItem]] = None,
         buttons: Optional[list[DeviceItem]] = None,
         hats: Optional[list[DeviceItem]] = None,
+        rotation: int = 0,
+        normalize_range: tuple[float, float] = (-1.0, 1.0),
+        raw_hats: bool = False,
+        uniq: Optional[str] = None,
+        phys: Optional[str] = None,
+        vendor_id: Optional[int] = None,
+        product_id: Optional[int] = None,
+        ignore: Optional[list[int]] = None,
+        grab: bool = False,
+        match_mode: str = "trimmed",
+        event_times: bool = False,
     ) -> None: ...
     @staticmethod
-    def from_toml(toml_file: str) -> DeviceDescription:
+    def from_toml(toml_file: str | os.PathLike[str]) -> DeviceDescription:
         """Create DeviceDescription from TOML file"""
         ...
 
+    @staticmethod
+    def from_toml_bytes(content: bytes) -> DeviceDescription:
+        """Create DeviceDescription from the UTF-8 bytes of a TOML document"""
+        ...
+
+    @staticmethod
+    def from_toml_str(content: str) -> DeviceDescription:
+        """Create DeviceDescription from a TOML string"""
+        ...
+
+    @staticmethod
+    def from_json_str(content: str) -> DeviceDescription:
+        """Create DeviceDescription from a JSON string"""
+        ...
+
+    @staticmethod
+    def from_json(json_file: str) -> DeviceDescription:
+        """Create DeviceDescription from JSON file"""
+        ...
+
+    @staticmethod
+    def from_file(path: str) -> DeviceDescription:
+        """Create DeviceDescription from a TOML or JSON file.
+
+        Files ending in `.json` are read as JSON, every other file as TOML.
+        """
+        ...
+
+    @staticmethod
+    def from_device(path: str) -> DeviceDescription:
+        """Create a DeviceDescription skeleton from a connected device.
+
+        Lists every supported axis, button and hat without aliases, named after the device.
+
+        Example:
+            >>> desc = DeviceDescription.from_device('/dev/input/event5')
+            >>> desc.save_toml('devices/new_stick.toml')
+
+        Raises:
+            IOError: If the device cannot be opened.
+        """
+        ...
+
+    @staticmethod
+    def from_sdl_mapping(
+        line: str, device: Optional[DeviceDescription] = None
+    ) -> DeviceDescription:
+        """Create a DeviceDescription from one line of SDL's gamecontrollerdb.txt.
+
+        The SDL names (a, b, x, y, leftx, lefttrigger, ...) become the aliases of the
+        inputs they are mapped onto, the dpad hat axes are aliased dpadx and dpady.
+        SDL numbers inputs in the order it finds them on the device, so pass the
+        description of the actual device (e.g. from from_device) for exact codes.
+        Without it, buttons are assumed to start at 288 and axes at 0, like on generic
+        HID joysticks.
+
+        Example:
+            >>> device = DeviceDescription.from_device('/dev/input/event5')
+            >>> desc = DeviceDescription.from_sdl_mapping(line, device)
+            >>> desc.button_code("a")
+            304
+
+        Raises:
+            ValueError: If the line is malformed or refers to inputs the device lacks.
+        """
+        ...
+
+    def to_toml(self) -> str:
+        """Serialize the description to TOML, leaving out unset optional fields"""
+        ...
+
+    def save_toml(self, path: str) -> None:
+        """Write the description to a TOML file.
+
+        Raises:
+            IOError: If the file cannot be written.
+        """
+        ...
+
+    def to_json(self) -> str:
+        """Serialize the description to a pretty-printed JSON string"""
+        ...
+
     def build_state(self) -> JoystickState:
         """Build state dictionary from device description"""
         ...
 
+    def state_to_vector(self, state: JoystickState) -> list[float]:
+        """Flatten a state into a fixed-layout vector.
+
+        The layout follows the declaration order of the description: every axis
+        (normalized value), then every button (0.0/1.0), then every hat
+        (-1.0/0.0/1.0). Inputs missing from the state are written as 0.0.
+
+        Example:
+            >>> vector = numpy.asarray(device.state_to_vector(state))
+        """
+        ...
+
+    def vector_to_state(self, vector: list[float]) -> JoystickState:
+        """Rebuild a state from a vector produced by `state_to_vector`.
+
+        Raises:
+            ValueError: If the vector length does not match the description.
+        """
+        ...
+
+    def merge(self, other: DeviceDescription) -> DeviceDescription:
+        """Combine this description with `other` into one logical device.
+
+        The result keeps this description's device_name and lists the physical
+        devices of both in `members`. A DevicePool loading it monitors every member
+        and reports their inputs together under that name.
+
+        Raises:
+            ValueError: If both declare the same axis, button or hat code, or
+                different non-zero rotations.
+        """
+        ...
+
+    def axis_code(self, alias: str) -> Optional[int]:
+        """Code of the first axis declared with `alias`, or None"""
+        ...
+
+    def axis_alias(self, code: int) -> Optional[str]:
+        """Alias of the axis with `code`, or None"""
+        ...
+
+    def button_code(self, alias: str) -> Optional[int]:
+        """Code of the first button declared with `alias`, or None
+
+        Example:
+            >>> trigger = device.button_code("Trigger")
+            >>> pressed = state.buttons[trigger]
+        """
+        ...
+
+    def button_alias(self, code: int) -> Optional[str]:
+        """Alias of the button with `code`, or None"""
+        ...
+
+    def hat_code(self, alias: str) -> Optional[int]:
+        """Code of the first hat declared with `alias`, or None"""
+        ...
+
+    def hat_alias(self, code: int) -> Optional[str]:
+        """Alias of the hat with `code`, or None"""
+        ...
+
 class PyJoystick:
     """Joystick class for managing a single joystick device.
 
     This class provides methods to initialize, read state, and manage a single joystick device.
     It handles the underlying device interactions and provides an easy-to-use interface for
     fetching joystick states.
+    A PyJoystick can be shared between threads, concurrent calls are serialized and
+    release the GIL while they wait for the device.
 
     Args:
         device_path: Path to the joystick device file
-        debounce_seconds: Time interval in seconds to debounce input events (default: 0.1)
+        description: Optional device description whose per-axis options (e.g. invert) are applied,
+            grabbing the device if it sets grab
+        max_events_per_poll: Optional limit on the events one read processes; further events
+            stay buffered for the next reads, bounding the time a read takes under an event flood
+
+    Attributes:
+        grabbed (bool): Whether this handle holds an exclusive grab of the device
 
     Methods:
+        by_name(name, description=None, max_events_per_poll=None): Open the first connected
+            joystick with this name instead of a device path
         get_state(): Fetch current state of the joystick, including axes, buttons, and hats
-        stop(): Stop the joystick and clean up resources
+        rumble(strong, weak, duration_ms): Play a dual-motor rumble effect
+        set_grab(grab): Grab the device for exclusive access or release the grab
+        axis_ranges(): (min, max) range of every axis whose range is known
+        capabilities(): Detected axis, button, hat and relative axis codes
 
     Example:
         >>> joystick = PyJoystick('/dev/input/js0')
@@ -116,8 +443,212 @@ class PyJoystick:
         >>> print(state.axes, state.buttons, state.hats)
     """
 
-    def __init__(self, device_path: str) -> None: ...
-    def get_state(self) -> JoystickState: ...
+    def __init__(
+        self,
+        device_path: str,
+        description: Optional[DeviceDescription] = None,
+        max_events_per_poll: Optional[int] = None,
+    ) -> None: ...
+    @staticmethod
+    def by_name(
+        name: str,
+        description: Optional[DeviceDescription] = None,
+        max_events_per_poll: Optional[int] = None,
+    ) -> "PyJoystick":
+        """Open the first connected joystick named name.
+
+        Device paths change across reboots and hotplugs, names do not.
+
+        Raises:
+            FileNotFoundError: If no connected joystick has this name
+        """
+        ...
+    def get_state(self) -> JoystickState:
+        """Read pending events, same as get_state_delta()"""
+        ...
+
+    def get_state_delta(self) -> JoystickState:
+        """Read the inputs reported since the previous read.
+
+        Only the inputs of this read are present, an idle device returns an empty state.
+        """
+        ...
+
+    def get_state_full(self) -> JoystickState:
+        """Read pending events and return every input reported since the device was opened.
+
+        Inputs keep their last reported value, pressed_this_cycle only covers this read.
+        """
+        ...
+
+    def rumble(self, strong: int, weak: int, duration_ms: int) -> None:
+        """Play a dual-motor rumble effect.
+
+        Args:
+            strong: Magnitude of the heavy motor (0-65535)
+            weak: Magnitude of the light motor (0-65535)
+            duration_ms: How long to rumble in milliseconds, capped at 65535
+
+        Raises:
+            IOError: If the device has no rumble force feedback or the effect cannot be played.
+        """
+        ...
+
+    @property
+    def grabbed(self) -> bool: ...
+    def set_grab(self, grab: bool) -> None:
+        """Grab the device so its events reach this handle only, or release the grab.
+
+        The grab is also released when the joystick is closed.
+
+        Raises:
+            IOError: If another process already holds the grab or the grab cannot be changed.
+        """
+        ...
+
+    def axis_ranges(self) -> dict[int, tuple[int, int]]:
+        """(min, max) range of every axis and hat whose range is known, keyed by axis code"""
+        ...
+
+    def capabilities(self) -> dict[str, list[int]]:
+        """Detected input codes under the keys "axes", "buttons", "hats" and "rel_axes", sorted ascending"""
+        ...
+
+class PyVirtualJoystick:
+    """Virtual joystick created through uinput.
+
+    The device gets exactly the axes, buttons and hats of the description and is named
+    after its device_name. Analog axes use the raw range -32768..32767, hats -1..1.
+    Creating one needs write access to /dev/uinput.
+
+    Args:
+        description: The description defining the inputs of the virtual device
+
+    Raises:
+        IOError: If the virtual device cannot be created.
+
+    Example:
+        >>> virtual = PyVirtualJoystick(description)
+        >>> state = joystick.get_state()
+        >>> state.axes[0] = -state.axes[0]
+        >>> virtual.emit(state)
+    """
+
+    def __init__(self, description: DeviceDescription) -> None: ...
+    def emit(self, state: JoystickState) -> None:
+        """Push a state out as one report of the virtual device.
+
+        Only inputs declared in the description and present in the state are written.
+        Axis values are read in the normalize_range of the description.
+
+        Raises:
+            IOError: If the events cannot be written.
+        """
+        ...
+
+class PyKeyMapper:
+    """Maps joystick buttons onto the keys of a uinput virtual keyboard.
+
+    Each bound key follows its button: pressing the button presses the key and
+    releasing it releases the key. The debounce time of the pool applies to every
+    transition. The virtual keyboard is created on the first key event, which needs
+    write access to /dev/uinput.
+
+    Example:
+        >>> mapper = PyKeyMapper()
+        >>> mapper.add_binding("Thrustmaster T.16000M", 288, 57)  # KEY_SPACE
+        >>> await pool.reset()
+        >>> await mapper.run(pool)
+    """
+
+    def __init__(self) -> None: ...
+    def add_binding(self, device_name: str, button_code: int, key_code: int) -> None:
+        """Bind a button to an evdev key code, replacing an earlier binding of it."""
+        ...
+
+    async def run(self, pool: PyDevicePool) -> None:
+        """Emit key events for the bound buttons until the pool is stopped.
+
+        The pool is fetched every 20 ms, which consumes its triggers like any other
+        fetch. Keys still pressed when the pool stops are released.
+
+        Raises:
+            RuntimeError: If the pool is not running.
+            IOError: If the virtual keyboard cannot be created or written to.
+        """
+        ...
+
+class PyComboMatcher:
+    """Detects sets of buttons of one device that are held at the same time.
+
+    A combo fires once when all of its buttons are held and fires again only after
+    one of them was released. Devices missing from a checked dict keep their combo
+    state, so the changed states returned by `PyDevicePool.fetch` can be passed in
+    directly.
+
+    Example:
+        >>> combos = PyComboMatcher()
+        >>> combos.add_combo("select_start", "Xbox Controller", [314, 315])
+        >>> states = await pool.fetch()
+        >>> if "select_start" in combos.check(states):
+        ...     print("menu")
+    """
+
+    def __init__(self) -> None: ...
+    def add_combo(self, name: str, device_name: str, button_codes: list[int]) -> None:
+        """Add a named combo, replacing an earlier combo of the same name."""
+        ...
+
+    def check(self, state_dict: dict[str, JoystickState]) -> list[str]:
+        """Update the combos from a fetch and return the names of those that just fired."""
+        ...
+
+    def active(self) -> list[str]:
+        """Return the names of the combos whose buttons are all held."""
+        ...
+
+class DeviceReport:
+    """Status snapshot of a single described device"""
+
+    device_name: str
+    connected: bool
+    monitored: bool
+    receiving_data: bool
+    last_error: Optional[str]
+
+class InputKind:
+    """Kind of input an InputEvent was reported by"""
+
+    Axis: InputKind
+    Button: InputKind
+    Hat: InputKind
+
+class InputEvent:
+    """A single input change tagged with its device, see PyDevicePool.events()
+
+    Attributes:
+        device (str): Name of the described device the input belongs to
+        kind (InputKind): Whether the input is an axis, a button or a hat
+        code (int): evdev code of the input
+        value (float): Processed axis value, 1.0/0.0 for a button press/release, -1.0/0.0/1.0 for a hat
+    """
+
+    device: str
+    kind: InputKind
+    code: int
+    value: float
+
+class PyStateStream:
+    """Async iterator over the changed states of a PyDevicePool, see PyDevicePool.stream()"""
+
+    def __aiter__(self) -> PyStateStream: ...
+    async def __anext__(self) -> dict[str, JoystickState] | dict[str, dict[str, dict]]: ...
+
+class PyActivityStream:
+    """Async iterator over the names of devices whose state changed, see PyDevicePool.subscribe()"""
+
+    def __aiter__(self) -> PyActivityStream: ...
+    async def __anext__(self) -> str: ...
 
 class PyDevicePool:
     """
@@ -127,14 +658,37 @@ class PyDevicePool:
     handling state fetching, and coordinating device interactions with built-in debouncing.
 
     Args:
-        device_desc_files: List of file paths containing device descriptions/configurations
+        device_desc_files: List of TOML or JSON file paths containing device descriptions/configurations
         debounce_seconds: Time interval in seconds to debounce input events (default: 0.1)
+        descriptions: Already parsed device descriptions, monitored alongside the files
+        hotplug: Re-scan connected devices every second while monitoring, so described
+            devices that are plugged in again resume without calling reset() (default: False)
+        use_aliases: Return fetched states as dicts (see JoystickState.to_dict) keyed by the
+            aliases of the device description, falling back to the numeric code for inputs
+            without an alias (default: False)
+        as_dict: Return fetched states as dicts keyed by input code (see
+            JoystickState.to_dict) instead of JoystickState objects (default: False)
+        max_open_retries: How often opening a connected device is retried before the pool
+            gives up on it, e.g. while the device is still busy after being plugged in (default: 0)
+        open_retry_delay_seconds: Delay before the first retry, doubled for every further
+            retry (default: 0.1)
+        change_epsilon: Axis changes smaller than this do not wake fetch(); buttons and hats
+            are compared exactly (default: 0.0, every change counts)
 
     Methods:
         reset(): Asynchronously reset all devices in the pool to their initial state
         fetch_nowait(): Non-blocking fetch of current joystick state, returns immediately
-        fetch(timeout_seconds=None): Asynchronously fetch joystick state with optional timeout
-        stop(): Gracefully stop the device pool and clean up resources
+        fetch_last(): Last known joystick state, also available after stop()
+        fetch(timeout_seconds=None, device_filter=None): Asynchronously fetch joystick state with
+            optional timeout, optionally restricted to some devices
+        wait_for_all_devices(timeout_seconds=None): Wait until every described device is connected
+        device_report(): Connection/monitoring status of every described device
+        device_health(): Whether the monitor task of each device is still running
+        last_errors(): Most recent open/read/panic error per device
+        debounced_codes(): (device_name, code) pairs currently suppressed by the debounce window
+        on_button(device_name, code, callback): Call a function whenever a button is pressed
+        stream(): Async iterator yielding changed states until stop() is called
+        stop(drain_timeout_seconds=None): Gracefully stop the device pool and clean up resources
 
     Example:
         >>> pool = PyDevicePool(['config1.toml', 'config2.toml'], debounce_seconds=0.05)
@@ -150,7 +704,16 @@ class PyDevicePool:
     """Device pool for managing joystick states"""
 
     def __init__(
-        self, device_desc_files: list[str], debounce_seconds: float = 0.1
+        self,
+        device_desc_files: list[str] = [],
+        debounce_seconds: float = 0.1,
+        descriptions: list[DeviceDescription] = [],
+        hotplug: bool = False,
+        use_aliases: bool = False,
+        as_dict: bool = False,
+        max_open_retries: int = 0,
+        open_retry_delay_seconds: float = 0.1,
+        change_epsilon: float = 0.0,
     ) -> None: ...
     async def reset(self) -> None:
         """Reset all devices in the pool to their initial state.
@@ -162,7 +725,28 @@ class PyDevicePool:
         """
         ...
 
-    def fetch_nowait(self) -> dict[str, JoystickState]:
+    async def start(self) -> list[str]:
+        """Start monitoring without resetting the pool.
+
+        Unlike `reset`, the current states and the debounce/hold bookkeeping are kept.
+        Does nothing if the pool is already running.
+
+        Returns:
+            The names of the connected described devices.
+        """
+        ...
+
+    async def reset_preserve_axes(self) -> list[str]:
+        """Reset the pool like `reset`, but keep the current axis values.
+
+        Buttons and hats are cleared, `axes` and `raw_axes` keep their positions.
+
+        Returns:
+            The names of the connected described devices.
+        """
+        ...
+
+    def fetch_nowait(self) -> dict[str, JoystickState] | dict[str, dict[str, dict]]:
         """Fetch current joystick state without blocking.
         This method retrieves the current state of all joysticks in the pool without waiting.
         It returns immediately with the latest state information.
@@ -180,20 +764,47 @@ class PyDevicePool:
         """
         ...
 
+    def fetch_nowait_changed(
+        self,
+    ) -> dict[str, JoystickState] | dict[str, dict[str, dict]] | None:
+        """Like fetch_nowait, but returns None when nothing changed since the last fetch.
+
+        Triggers are only reset when a changed state is returned.
+
+        Raises:
+            RuntimeError: If the device pool is not running.
+        """
+        ...
+
+    def fetch_last(self) -> dict[str, JoystickState] | dict[str, dict[str, dict]]:
+        """Return the last known joystick state without any checks.
+        Unlike fetch_nowait(), this works while monitoring is stopped and does not
+        reset button/hat triggers, so it can be used to inspect the final state after stop().
+        Returns:
+            dict[str, JoystickState]: A dictionary mapping joystick names to their last state.
+        """
+        ...
+
     async def fetch(
-        self, timeout_seconds: Optional[float] = None
-    ) -> dict[str, JoystickState]:
+        self,
+        timeout_seconds: Optional[float] = None,
+        device_filter: Optional[list[str]] = None,
+        return_on_timeout: bool = False,
+    ) -> dict[str, JoystickState] | dict[str, dict[str, dict]]:
         """Fetch current joystick state with optional timeout.
         This method retrieves the current state of all joysticks in the pool, waiting for
         the specified timeout if provided. If no timeout is specified, it will wait indefinitely
         until the state is available.
         Raises:
-            RuntimeError: If the device pool has not been initialized or is not running.
-            TimeoutError: If the operation times out before fetching the state.
+            asyncio.TimeoutError: If the operation times out before fetching the state.
 
         Args:
             timeout_seconds (Optional[float], optional): Timeout in seconds for the fetch operation.
                 If None, it will wait indefinitely. Defaults to None.
+            device_filter (Optional[list[str]], optional): Only wait for and return changes of
+                these devices. Changes to other devices stay pending. Defaults to None (all devices).
+            return_on_timeout (bool, optional): Return the current state instead of raising
+                asyncio.TimeoutError when the timeout expires. Defaults to False.
 
         Returns:
             dict[str, JoystickState]: A dictionary mapping joystick names to their current state.
@@ -206,18 +817,195 @@ class PyDevicePool:
             ...     states = await device_pool.fetch(timeout_seconds=2.0)
             ...     for name, state in states.items():
             ...         print(f"{name}: {state.axes}, {state.buttons}, {state.hats}")
-        except TimeoutError:
+        except asyncio.TimeoutError:
             print("Fetching joystick state timed out. No state available.")
+        """
+
+    async def events(self, timeout_seconds: Optional[float] = None) -> list[InputEvent]:
+        """Return the input events of all devices reported since the previous call.
+
+        Waits until at least one event arrives or the timeout expires. The event log
+        is independent of fetch and is cleared by reset().
+
+        Args:
+            timeout_seconds (Optional[float], optional): Timeout in seconds.
+                If None, it will wait indefinitely. Defaults to None.
+
+        Returns:
+            list[InputEvent]: The events in report order, empty if the timeout expired.
+        """
+
+    async def fetch_device(
+        self, device_name: str, timeout_seconds: Optional[float] = None
+    ) -> JoystickState | dict[str, dict]:
+        """Fetch the state of a single device, waiting for it to change.
+
+        Works like fetch with device_filter=[device_name], but returns only that
+        device's state instead of a dictionary.
+
+        Args:
+            device_name (str): The name of the device to fetch.
+            timeout_seconds (Optional[float], optional): Timeout in seconds.
+                If None, it will wait indefinitely. Defaults to None.
+
         Raises:
-            RuntimeError: If the device pool has not been initialized or is not running.
-            TimeoutError: If the operation times out before fetching the state.
+            KeyError: If no description of the device is loaded.
+            asyncio.TimeoutError: If the device does not change before the timeout.
+
+        Returns:
+            JoystickState | dict[str, dict]: The device state, formatted like fetch.
+        """
+
+    async def wait_for_all_devices(
+        self, timeout_seconds: Optional[float] = None
+    ) -> list[str]:
+        """Wait until every described device is connected.
+        The connected devices are re-enumerated periodically until each loaded
+        description has a matching connected device.
+        Args:
+            timeout_seconds (Optional[float], optional): Maximum time to wait.
+                If None, it will wait indefinitely. Defaults to None.
+        Returns:
+            list[str]: Names of the connected described devices.
+        Raises:
+            asyncio.TimeoutError: If the timeout expires, like fetch(); the message lists
+                the missing devices.
+        Example:
+            >>> await device_pool.wait_for_all_devices(timeout_seconds=10.0)
+            >>> await device_pool.reset()
+        """
+        ...
+
+    def is_running(self) -> bool:
+        """Whether the pool is monitoring its devices (after reset(), until stop())"""
+        ...
+
+    def pause(self) -> None:
+        """Stop updating the state without stopping monitoring.
+
+        The monitor tasks keep reading their devices but discard the inputs, so the
+        state stays as it was. Independent of stop() and reset().
+        """
+        ...
+
+    def resume(self) -> None:
+        """Update the state again after pause(); inputs from the pause are not replayed"""
+        ...
+
+    def is_paused(self) -> bool:
+        """Whether state updates are paused"""
+        ...
+
+    def monitored_devices(self) -> list[str]:
+        """Names of the devices the pool reports states for, in load order.
+
+        Listed whether or not the devices are connected, see device_report() for that.
+        """
+        ...
+
+    def load_errors(self) -> list[tuple[str, str]]:
+        """(path, error) of every device_desc_files entry that could not be loaded.
+
+        Such files are skipped, so their devices are missing from the pool.
         """
+        ...
+
+    def validate_against_hardware(self) -> dict[str, list[int]]:
+        """Described axis, button and hat codes each connected device does not have.
+
+        Opens every connected device matching a description and compares its
+        capabilities with the description. Devices that are not connected or cannot
+        be opened are left out; an empty list means the description fits the device.
+        """
+        ...
 
-    async def stop(self) -> None:
+    def device_health(self) -> dict[str, bool]:
+        """Whether the monitor task of each device is still running.
+
+        A device turns False once its monitor task has exited, whether it returned,
+        crashed or was stopped. Devices that never had a monitor task are absent.
+        """
+        ...
+
+    def device_report(self) -> list[DeviceReport]:
+        """Return a status report for every described device.
+        Each report combines whether the device is connected, whether a monitor
+        task is reading it, whether any input has arrived since monitoring started,
+        and the last open/read error seen by the monitor.
+        Returns:
+            list[DeviceReport]: One report per loaded description, in load order.
+        Example:
+            >>> for report in device_pool.device_report():
+            ...     print(report.device_name, report.connected, report.monitored)
+        """
+        ...
+
+    def last_errors(self) -> dict[str, str]:
+        """Return the most recent error recorded for each device.
+        Errors come from failed device opens, failed reads and crashed
+        (panicked) monitor tasks. Devices without errors are omitted.
+        Returns:
+            dict[str, str]: A dictionary mapping device names to error messages.
+        """
+        ...
+
+    def debounced_codes(self) -> list[tuple[str, int]]:
+        """Return the button/hat codes currently inside their debounce window.
+        A code is listed while its last accepted press is younger than
+        `debounce_seconds`, i.e. while further presses of it are suppressed.
+        Debouncing is per device, so each code comes with its device name.
+        Returns:
+            list[tuple[str, int]]: (device_name, code) pairs, sorted by device and code.
+        Example:
+            >>> print(device_pool.debounced_codes())
+            [('T.A320 Pilot', 288), ('T.A320 Pilot', 289)]
+        """
+        ...
+
+    def on_button(
+        self, device_name: str, code: int, callback: Callable[[str, int], object]
+    ) -> None:
+        """Call `callback(device_name, code)` whenever the button goes from released to pressed.
+        Note:
+            Callbacks run one after the other, in press order, on a single worker thread
+            of the pool and acquire the GIL there, not on the asyncio event loop. Presses
+            suppressed by the debounce window do not fire. Keep them short, and use
+            `loop.call_soon_threadsafe` to hand work over to asyncio code.
+            Exceptions raised by a callback are logged with their traceback and otherwise ignored.
+        Example:
+            >>> device_pool.on_button("T.A320 Pilot", 288, lambda name, code: print("fire"))
+        """
+        ...
+
+    def stream(self) -> PyStateStream:
+        """Iterate over changed states with `async for`.
+        Each step waits like fetch() without a timeout, iteration ends once stop() was called.
+        Example:
+            >>> async for states in device_pool.stream():
+            ...     print(states)
+        """
+        ...
+
+    def subscribe(self) -> PyActivityStream:
+        """Iterate over the names of devices whose state changed with `async for`.
+        A name is yielded for every recorded change without consuming it, so subscribers
+        do not interfere with fetch(). Changes older than the subscription are not seen,
+        iteration ends once stop() was called.
+        Example:
+            >>> async for device_name in device_pool.subscribe():
+            ...     print(f"{device_name} is active")
+        """
+        ...
+
+    async def stop(self, drain_timeout_seconds: Optional[float] = None) -> None:
         """Stop the device pool and clean up resources.
         This method gracefully stops the device pool, ensuring all resources are cleaned up
         and no further state fetching can occur. It should be called when the device pool is no
         longer needed to prevent resource leaks.
+        Args:
+            drain_timeout_seconds: How long to wait for the monitor tasks to finish on their
+                own, None to wait as long as it takes. Tasks still running afterwards are
+                cancelled; either way no task updates the state once stop() returns.
         Raises:
             RuntimeError: If the device pool is not running or has already been stopped.
         Note:
@@ -0,0 +1,121 @@
+use crate::utils::JoystickState;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Detects button combos, i.e. sets of buttons of one device held at the same time.
+///
+/// A combo fires once when the last of its buttons goes down while the others are
+/// held (`JoystickState::held`), and can only fire again after at least one of its
+/// buttons was released. Devices missing from a checked fetch keep their combo state,
+/// so the matcher can be fed the changed devices of `DevicePool::fetch` directly.
+///
+/// # Fields
+///
+/// * `combos` - The device and button codes of each named combo
+/// * `active` - Whether each combo is currently held
+#[derive(Debug, Clone, Default)]
+pub struct ComboMatcher {
+    combos: BTreeMap<String, (String, BTreeSet<u16>)>,
+    active: BTreeMap<String, bool>,
+}
+
+impl ComboMatcher {
+    /// Creates a matcher without combos.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the combo `name`, replacing an earlier combo of the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name reported when the combo fires
+    /// * `device_name` - The device the buttons belong to
+    /// * `button_codes` - The evdev codes of the buttons that must be held together
+    pub fn add_combo(
+        &mut self,
+        name: &str,
+        device_name: &str,
+        button_codes: impl IntoIterator<Item = u16>,
+    ) {
+        let codes = button_codes.into_iter().collect();
+        self.combos
+            .insert(name.to_string(), (device_name.to_string(), codes));
+        self.active.insert(name.to_string(), false);
+    }
+
+    /// Updates the combos from the button levels in `states`.
+    ///
+    /// # Arguments
+    ///
+    /// * `states` - The states of a pool fetch, keyed by device name
+    ///
+    /// # Returns
+    /// The names of the combos that fired with this fetch, in name order.
+    pub fn check(&mut self, states: &HashMap<String, JoystickState>) -> Vec<String> {
+        let mut fired = Vec::new();
+
+        for (name, (device_name, codes)) in &self.combos {
+            let Some(state) = states.get(device_name) else {
+                continue;
+            };
+            let held = !codes.is_empty()
+                && codes
+                    .iter()
+                    .all(|code| state.held.get(code).is_some_and(|&level| level != 0));
+            let was_active = self.active.insert(name.clone(), held) == Some(true);
+            if held && !was_active {
+                fired.push(name.clone());
+            }
+        }
+
+        fired
+    }
+
+    /// The names of the combos whose buttons are all held, in name order.
+    pub fn active(&self) -> Vec<String> {
+        self.active
+            .iter()
+            .filter(|(_, &active)| active)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn states(held: &[(u16, u8)]) -> HashMap<String, JoystickState> {
+        let mut state = JoystickState::new();
+        state.held.extend(held.iter().copied());
+        HashMap::from([("stick".to_string(), state)])
+    }
+
+    #[test]
+    fn test_two_button_combo_fires_once_per_press() {
+        let mut matcher = ComboMatcher::new();
+        matcher.add_combo("select_start", "stick", [314, 315]);
+
+        assert!(matcher.check(&states(&[(314, 1), (315, 0)])).is_empty());
+        assert!(matcher.active().is_empty());
+
+        assert_eq!(
+            matcher.check(&states(&[(314, 1), (315, 1)])),
+            vec!["select_start".to_string()]
+        );
+        assert_eq!(matcher.active(), vec!["select_start".to_string()]);
+        // Keeping both buttons held does not fire again
+        assert!(matcher.check(&states(&[(314, 1), (315, 1)])).is_empty());
+        // A fetch without the device keeps the combo held
+        assert!(matcher.check(&HashMap::new()).is_empty());
+        assert_eq!(matcher.active(), vec!["select_start".to_string()]);
+
+        // Releasing one button re-arms the combo
+        assert!(matcher.check(&states(&[(314, 1), (315, 0)])).is_empty());
+        assert!(matcher.active().is_empty());
+        assert_eq!(
+            matcher.check(&states(&[(314, 1), (315, 1)])),
+            vec!["select_start".to_string()]
+        );
+    }
+}
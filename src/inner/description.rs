@@ -1,10 +1,16 @@
-use crate::utils::JoystickState;
+use crate::inner::joystick::Joystick;
+use crate::utils::{JoystickInfo, JoystickState};
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
+use std::path::Path;
+#[cfg(feature = "python")]
+use std::path::PathBuf;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 /// Represents a single device item with a unique code and optional alias.
 ///
 /// This structure is used to identify and reference specific device components
@@ -15,6 +21,25 @@ use std::fs;
 ///
 /// * `code` - A unique 16-bit identifier for the device item
 /// * `alias` - An optional string alias that provides a more descriptive name
+/// * `invert` - Whether the normalized value of an axis is negated (defaults to false)
+/// * `debounce_ms` - Debounce time of a button or hat in milliseconds, overriding the
+///   pool default
+/// * `smoothing` - Exponential moving average factor of an axis, between 0 (exclusive)
+///   and 1 (no smoothing)
+/// * `button_code` - Code of a synthetic button an axis presses while its normalized value
+///   is at or above `button_threshold`, e.g. for analog triggers
+/// * `button_threshold` - The threshold of `button_code` (defaults to 0.5)
+/// * `calib_min`, `calib_center`, `calib_max` - Raw readings of an axis at its physical
+///   minimum, centre and maximum, overriding the range reported by the device
+/// * `track_crossings` - Whether a pool records when the axis crosses 0.0, see
+///   `JoystickState::axis_crossings`. On a rotated device the crossings are keyed by
+///   the logical axis the physical one maps to
+/// * `curve` - Response curve applied to the normalized magnitude of an axis, see
+///   [`AxisCurve`] (defaults to linear)
+/// * `initial` - The value an axis reports before its first event, e.g. -1.0 for a
+///   throttle resting at the bottom (defaults to 0.0)
+/// * `axis` - Which axis of a hat the item stands for, see [`HatAxis`]; `code` may then
+///   be the code of either axis of the hat
 ///
 /// # Examples
 ///
@@ -22,6 +47,18 @@ use std::fs;
 /// let device_item = DeviceItem {
 ///     code: 0x1001,
 ///     alias: Some("Temperature Sensor".to_string()),
+///     invert: false,
+///     debounce_ms: None,
+///     smoothing: None,
+///     button_code: None,
+///     button_threshold: None,
+///     calib_min: None,
+///     calib_center: None,
+///     calib_max: None,
+///     track_crossings: false,
+///     curve: AxisCurve::Linear,
+///     initial: None,
+///     axis: None,
 /// };
 /// ```
 ///
@@ -31,40 +68,398 @@ use std::fs;
 /// accessed as read-only properties from Python code.
 pub struct DeviceItem {
     /// The code of the device item
-    #[pyo3(get)]
     pub code: u16,
     /// An alias for the device item, used for easier reference
-    #[pyo3(get)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub alias: Option<String>,
+    /// Whether an axis reports its normalized value negated, e.g. for pitch axes
+    #[serde(default)]
+    pub invert: bool,
+    /// Debounce time of a button or hat, `None` uses the debounce time of the pool
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debounce_ms: Option<u64>,
+    /// Smoothing factor of an axis, each stored value is `alpha * new + (1 - alpha) * previous`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub smoothing: Option<f32>,
+    /// Code of the synthetic button driven by an axis
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub button_code: Option<u16>,
+    /// Normalized axis value from which the synthetic button reads as pressed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub button_threshold: Option<f32>,
+    /// Raw reading of an axis at its physical minimum
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calib_min: Option<i32>,
+    /// Raw reading of an axis at its physical centre
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calib_center: Option<i32>,
+    /// Raw reading of an axis at its physical maximum
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calib_max: Option<i32>,
+    /// Whether the pool records the direction in which an axis crosses 0.0
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub track_crossings: bool,
+    /// Response curve applied to the normalized magnitude of an axis
+    #[serde(default, skip_serializing_if = "AxisCurve::is_linear")]
+    pub curve: AxisCurve,
+    /// Value of an axis before the device reported it, `None` for 0.0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial: Option<f32>,
+    /// The hat axis the item stands for, `None` uses `code` as is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub axis: Option<HatAxis>,
 }
 
+/// The threshold of a synthetic axis button unless `button_threshold` says otherwise.
+pub const DEFAULT_BUTTON_THRESHOLD: f32 = 0.5;
+
+#[cfg(feature = "python")]
 #[pymethods]
-/// Creates a new `DeviceItem` with the specified code and optional alias.
+/// Creates a new `DeviceItem` with the specified code, optional alias and options.
 ///
 /// # Arguments
 ///
 /// * `code` - A 16-bit unsigned integer representing the device code
 /// * `alias` - An optional string alias for the device
+/// * `invert` - Whether the normalized axis value is negated
+/// * `debounce_ms` - Debounce time of a button or hat, overriding the pool default
+/// * `smoothing` - Exponential moving average factor of an axis
+/// * `button_code` - Code of a synthetic button pressed by an axis crossing a threshold
+/// * `button_threshold` - The threshold of `button_code`
+/// * `calib_min`, `calib_center`, `calib_max` - Calibrated raw readings of an axis
+/// * `track_crossings` - Whether a pool records when the axis crosses 0.0
+/// * `curve` - Response curve of an axis: `"linear"`, `"quadratic"` or `"power:N"`
+/// * `initial` - The value of an axis before its first event
+/// * `axis` - The axis of a hat the item stands for: `"x"` or `"y"`
 ///
 /// # Returns
 ///
 /// Returns a new instance of `DeviceItem` with the provided code and alias.
-///
-/// # Examples
-///
-/// ```
-/// let device = DeviceItem::new(0x1234, Some("My Device".to_string()));
-/// let device_no_alias = DeviceItem::new(0x5678, None);
-/// ```
 impl DeviceItem {
     #[new]
-    fn new(code: u16, alias: Option<String>) -> Self {
-        Self { code, alias }
+    #[pyo3(signature = (
+        code,
+        alias = None,
+        invert = false,
+        debounce_ms = None,
+        smoothing = None,
+        button_code = None,
+        button_threshold = None,
+        calib_min = None,
+        calib_center = None,
+        calib_max = None,
+        track_crossings = false,
+        curve = "linear",
+        initial = None,
+        axis = None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
+        code: u16,
+        alias: Option<String>,
+        invert: bool,
+        debounce_ms: Option<u64>,
+        smoothing: Option<f32>,
+        button_code: Option<u16>,
+        button_threshold: Option<f32>,
+        calib_min: Option<i32>,
+        calib_center: Option<i32>,
+        calib_max: Option<i32>,
+        track_crossings: bool,
+        curve: &str,
+        initial: Option<f32>,
+        axis: Option<&str>,
+    ) -> PyResult<Self> {
+        let curve = AxisCurve::try_from(curve.to_string())
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        let axis = axis
+            .map(|axis| HatAxis::try_from(axis.to_string()))
+            .transpose()
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        Ok(Self {
+            calib_min,
+            calib_center,
+            calib_max,
+            invert,
+            debounce_ms,
+            smoothing,
+            button_code,
+            button_threshold,
+            track_crossings,
+            curve,
+            initial,
+            axis,
+            ..Self::new(code, alias)
+        })
+    }
+}
+
+impl DeviceItem {
+    /// Returns the calibrated `(min, center, max)` readings of an axis, `None` if none
+    /// of them is declared.
+    ///
+    /// Readings left out are taken from `range`, the range reported by the device, and
+    /// the centre defaults to the middle of the range. `None` is also returned when the
+    /// range is incomplete.
+    pub fn calibration(&self, range: Option<(i32, i32)>) -> Option<(i32, i32, i32)> {
+        if self.calib_min.is_none() && self.calib_center.is_none() && self.calib_max.is_none() {
+            return None;
+        }
+        let min = self.calib_min.or(range.map(|(min, _)| min))?;
+        let max = self.calib_max.or(range.map(|(_, max)| max))?;
+        let center = self
+            .calib_center
+            .unwrap_or(((i64::from(min) + i64::from(max)) / 2) as i32);
+        Some((min, center, max))
+    }
+
+    /// Creates a new `DeviceItem` with the specified code and optional alias.
+    ///
+    /// All other options keep their defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let device = DeviceItem::new(0x1234, Some("My Device".to_string()));
+    /// let device_no_alias = DeviceItem::new(0x5678, None);
+    /// ```
+    pub fn new(code: u16, alias: Option<String>) -> Self {
+        Self {
+            code,
+            alias,
+            invert: false,
+            debounce_ms: None,
+            smoothing: None,
+            button_code: None,
+            button_threshold: None,
+            calib_min: None,
+            calib_center: None,
+            calib_max: None,
+            track_crossings: false,
+            curve: AxisCurve::Linear,
+            initial: None,
+            axis: None,
+        }
+    }
+
+    /// Points `code` at the declared `axis` of its hat.
+    ///
+    /// Hat codes come in X/Y pairs (`ABS_HAT0X`/`ABS_HAT0Y` through `ABS_HAT3X`/`ABS_HAT3Y`),
+    /// so an item with `axis` may name its hat by either code. Items without `axis` or
+    /// with a code outside the hat range are left alone; resolving twice changes nothing.
+    fn resolve_hat_axis(&mut self) {
+        const HAT0X: u16 = evdev::AbsoluteAxisCode::ABS_HAT0X.0;
+        const HAT3Y: u16 = evdev::AbsoluteAxisCode::ABS_HAT3Y.0;
+        if let Some(axis) = self.axis {
+            if (HAT0X..=HAT3Y).contains(&self.code) {
+                let x_code = self.code - (self.code - HAT0X) % 2;
+                self.code = x_code + u16::from(axis == HatAxis::Y);
+            }
+        }
+    }
+}
+
+/// Hands an option type to Python in the form it is written in a description file.
+#[cfg(feature = "python")]
+macro_rules! into_pyobject_as {
+    ($ty:ty, $repr:ty) => {
+        impl<'py> IntoPyObject<'py> for $ty {
+            type Target = <$repr as IntoPyObject<'py>>::Target;
+            type Output = <$repr as IntoPyObject<'py>>::Output;
+            type Error = <$repr as IntoPyObject<'py>>::Error;
+
+            fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+                <$repr>::from(self).into_pyobject(py)
+            }
+        }
+    };
+}
+
+/// The axis of a hat a [`DeviceItem`] stands for.
+///
+/// A hat reports two axes, X for left/right and Y for up/down, under neighbouring codes.
+/// Declaring the axis lets a description name both items after the hat's X code and
+/// alias them separately, e.g. "dpad_x" and "dpad_y". Written as `"x"` or `"y"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum HatAxis {
+    X,
+    Y,
+}
+
+impl TryFrom<String> for HatAxis {
+    type Error = String;
+
+    fn try_from(axis: String) -> Result<Self, Self::Error> {
+        match axis.as_str() {
+            "x" => Ok(HatAxis::X),
+            "y" => Ok(HatAxis::Y),
+            _ => Err(format!(
+                "Unsupported hat axis {:?}, expected \"x\" or \"y\"",
+                axis
+            )),
+        }
+    }
+}
+
+impl From<HatAxis> for String {
+    fn from(axis: HatAxis) -> Self {
+        match axis {
+            HatAxis::X => "x",
+            HatAxis::Y => "y",
+        }
+        .to_string()
+    }
+}
+
+#[cfg(feature = "python")]
+into_pyobject_as!(HatAxis, String);
+
+/// Response curve shaping the normalized value of an axis.
+///
+/// The curve maps the magnitude of a value in `[-1.0, 1.0]` and keeps its sign, so a
+/// `Power(2.0)` curve turns 0.5 into 0.25 and -0.5 into -0.25. Exponents above 1 give
+/// finer control around the centre while still reaching full deflection.
+///
+/// In a description the curve is written as `"linear"`, `"quadratic"` (`Power(2.0)`)
+/// or `"power:N"` with a positive exponent `N`, e.g. `"power:3"` for a cubic curve.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum AxisCurve {
+    #[default]
+    Linear,
+    Power(f32),
+}
+
+impl AxisCurve {
+    /// Applies the curve to a normalized axis value, preserving its sign.
+    pub fn apply(self, value: f32) -> f32 {
+        match self {
+            AxisCurve::Linear => value,
+            AxisCurve::Power(exponent) => value.signum() * value.abs().powf(exponent),
+        }
+    }
+
+    /// Returns true for the linear curve, which leaves values unchanged.
+    pub fn is_linear(&self) -> bool {
+        *self == AxisCurve::Linear
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+impl TryFrom<String> for AxisCurve {
+    type Error = String;
+
+    fn try_from(curve: String) -> Result<Self, Self::Error> {
+        match curve.as_str() {
+            "linear" => Ok(AxisCurve::Linear),
+            "quadratic" => Ok(AxisCurve::Power(2.0)),
+            _ => {
+                let exponent = curve
+                    .strip_prefix("power:")
+                    .and_then(|exponent| exponent.trim().parse::<f32>().ok())
+                    .filter(|exponent| exponent.is_finite() && *exponent > 0.0)
+                    .ok_or_else(|| {
+                        format!(
+                            "Unsupported curve {:?}, expected \"linear\", \"quadratic\" or \"power:N\" with N > 0",
+                            curve
+                        )
+                    })?;
+                Ok(AxisCurve::Power(exponent))
+            }
+        }
+    }
+}
+
+impl From<AxisCurve> for String {
+    fn from(curve: AxisCurve) -> Self {
+        match curve {
+            AxisCurve::Linear => "linear".to_string(),
+            AxisCurve::Power(2.0) => "quadratic".to_string(),
+            AxisCurve::Power(exponent) => format!("power:{}", exponent),
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+into_pyobject_as!(AxisCurve, String);
+
+/// How the `device_name` of a description is compared with the names of connected devices.
+///
+/// evdev names sometimes carry trailing whitespace or a vendor prefix missing from the
+/// configured name. Every mode but `Exact` ignores surrounding whitespace on both sides.
+///
+/// In a description the mode is written as `"exact"`, `"trimmed"` (the default),
+/// `"case_insensitive"` or `"substring"`. A `Substring` description matches every
+/// device whose name contains its `device_name`, ignoring case.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum NameMatch {
+    Exact,
+    #[default]
+    Trimmed,
+    CaseInsensitive,
+    Substring,
+}
+
+impl NameMatch {
+    /// Returns true if the connected device name `actual` matches the configured `expected`.
+    pub fn matches(self, expected: &str, actual: &str) -> bool {
+        let (expected_trimmed, actual_trimmed) = (expected.trim(), actual.trim());
+        match self {
+            NameMatch::Exact => expected == actual,
+            NameMatch::Trimmed => expected_trimmed == actual_trimmed,
+            NameMatch::CaseInsensitive => {
+                expected_trimmed.to_lowercase() == actual_trimmed.to_lowercase()
+            }
+            NameMatch::Substring => {
+                !expected_trimmed.is_empty()
+                    && actual_trimmed
+                        .to_lowercase()
+                        .contains(&expected_trimmed.to_lowercase())
+            }
+        }
+    }
+
+    /// Returns true for the default mode, which is left out of serialized descriptions.
+    pub fn is_default(&self) -> bool {
+        *self == NameMatch::default()
+    }
+}
+
+impl TryFrom<String> for NameMatch {
+    type Error = String;
+
+    fn try_from(mode: String) -> Result<Self, Self::Error> {
+        match mode.as_str() {
+            "exact" => Ok(NameMatch::Exact),
+            "trimmed" => Ok(NameMatch::Trimmed),
+            "case_insensitive" => Ok(NameMatch::CaseInsensitive),
+            "substring" => Ok(NameMatch::Substring),
+            _ => Err(format!(
+                "Unsupported match mode {:?}, expected \"exact\", \"trimmed\", \"case_insensitive\" or \"substring\"",
+                mode
+            )),
+        }
+    }
+}
+
+impl From<NameMatch> for String {
+    fn from(mode: NameMatch) -> Self {
+        match mode {
+            NameMatch::Exact => "exact",
+            NameMatch::Trimmed => "trimmed",
+            NameMatch::CaseInsensitive => "case_insensitive",
+            NameMatch::Substring => "substring",
+        }
+        .to_string()
+    }
+}
+
+#[cfg(feature = "python")]
+into_pyobject_as!(NameMatch, String);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
 /// Represents a complete description of an input device configuration.
 ///
 /// This struct contains metadata about the device as well as definitions for all
@@ -80,6 +475,25 @@ impl DeviceItem {
 /// * `axes` - Vector of axis input definitions (defaults to empty if not specified)
 /// * `buttons` - Vector of button input definitions (defaults to empty if not specified)
 /// * `hats` - Vector of hat/D-pad input definitions (defaults to empty if not specified)
+/// * `rotation` - Mounting rotation of the device applied to the X/Y axes (defaults to 0)
+/// * `members` - Names of the physical devices that make up a merged logical device
+///   (empty for a description of a single device)
+/// * `normalize_range` - The `(min, max)` range analog axes are normalized to
+///   (defaults to `(-1.0, 1.0)`)
+/// * `raw_hats` - Keep the hat values reported by evdev instead of clamping them to
+///   -1, 0 or 1 (defaults to false)
+/// * `uniq` - Optional evdev unique ID the device must report to match
+/// * `phys` - Optional evdev physical path the device must report to match
+/// * `vendor_id` - Optional USB vendor ID matching the device regardless of its name
+/// * `product_id` - Optional USB product ID matching the device regardless of its name
+/// * `ignore` - Axis, button and hat codes whose events are dropped by the device pool,
+///   for phantom inputs such as `ABS_MISC` (defaults to empty)
+/// * `grab` - Grab the device exclusively while it is open, so its events do not reach
+///   other applications (defaults to false)
+/// * `match_mode` - How `device_name` is compared with the names of connected devices
+///   (defaults to [`NameMatch::Trimmed`])
+/// * `event_times` - Keep the kernel timestamp of every input event in
+///   `JoystickState::event_time_us`, for latency analysis (defaults to false)
 ///
 /// # Examples
 ///
@@ -92,34 +506,185 @@ impl DeviceItem {
 ///     axes: vec![],
 ///     buttons: vec![],
 ///     hats: vec![],
+///     rotation: AxisRotation::Deg0,
+///     members: vec![],
+///     normalize_range: (-1.0, 1.0),
+///     raw_hats: false,
+///     uniq: None,
+///     phys: None,
+///     vendor_id: None,
+///     product_id: None,
+///     ignore: vec![],
+///     grab: false,
+///     match_mode: NameMatch::Trimmed,
+///     event_times: false,
 /// };
 /// ```
 pub struct DeviceDescription {
     #[serde(default = "default_device_name")]
-    #[pyo3(get)]
     pub device_name: String,
-    #[pyo3(get)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author: Option<String>,
-    #[pyo3(get)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub created: Option<String>,
-    #[pyo3(get)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(default)]
-    #[pyo3(get)]
     pub axes: Vec<DeviceItem>,
     #[serde(default)]
-    #[pyo3(get)]
     pub buttons: Vec<DeviceItem>,
     #[serde(default)]
-    #[pyo3(get)]
     pub hats: Vec<DeviceItem>,
+    #[serde(default)]
+    pub rotation: AxisRotation,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<String>,
+    #[serde(
+        default = "default_normalize_range",
+        skip_serializing_if = "is_default_normalize_range"
+    )]
+    pub normalize_range: (f32, f32),
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub raw_hats: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uniq: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phys: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vendor_id: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<u16>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<u16>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub grab: bool,
+    #[serde(default, skip_serializing_if = "NameMatch::is_default")]
+    pub match_mode: NameMatch,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub event_times: bool,
 }
 
+/// The range analog axes are normalized to unless a description asks otherwise.
+pub const DEFAULT_NORMALIZE_RANGE: (f32, f32) = (-1.0, 1.0);
+
 fn default_device_name() -> String {
     "Unknown Device".to_string()
 }
 
-#[pymethods]
+fn default_normalize_range() -> (f32, f32) {
+    DEFAULT_NORMALIZE_RANGE
+}
+
+fn is_default_normalize_range(range: &(f32, f32)) -> bool {
+    *range == DEFAULT_NORMALIZE_RANGE
+}
+
+/// Whether `path` names a JSON description file.
+fn is_json_path(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+/// Finds the code of the first item declared with `alias`.
+fn find_code(items: &[DeviceItem], alias: &str) -> Option<u16> {
+    items
+        .iter()
+        .find(|item| item.alias.as_deref() == Some(alias))
+        .map(|item| item.code)
+}
+
+/// Finds the alias of the first item declared with `code`.
+fn find_alias(items: &[DeviceItem], code: u16) -> Option<String> {
+    items
+        .iter()
+        .find(|item| item.code == code)
+        .and_then(|item| item.alias.clone())
+}
+
+/// The aliases declared in a description, per input kind and keyed by input code.
+///
+/// Axes, buttons and hats are kept apart because their codes live in different
+/// evdev code spaces and may overlap.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputAliases {
+    pub axes: HashMap<u16, String>,
+    pub buttons: HashMap<u16, String>,
+    pub hats: HashMap<u16, String>,
+}
+
+/// Mounting rotation of a stick, used to map physical X/Y onto logical X/Y.
+///
+/// The rotation is written in the description as clockwise degrees and the
+/// reported `(X, Y)` vector is rotated clockwise by that amount:
+///
+/// | rotation | logical X | logical Y |
+/// |----------|-----------|-----------|
+/// | 0        | X         | Y         |
+/// | 90       | Y         | -X        |
+/// | 180      | -X        | -Y        |
+/// | 270      | -Y        | X         |
+///
+/// Only `ABS_X` and `ABS_Y` are affected, every other axis passes through unchanged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "u16", into = "u16")]
+pub enum AxisRotation {
+    #[default]
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+impl AxisRotation {
+    /// Maps a normalized physical axis value onto its logical axis code and value.
+    pub fn apply(self, code: u16, value: f32) -> (u16, f32) {
+        const X: u16 = evdev::AbsoluteAxisCode::ABS_X.0;
+        const Y: u16 = evdev::AbsoluteAxisCode::ABS_Y.0;
+
+        match (self, code) {
+            (AxisRotation::Deg90, X) => (Y, -value),
+            (AxisRotation::Deg90, Y) => (X, value),
+            (AxisRotation::Deg180, X) | (AxisRotation::Deg180, Y) => (code, -value),
+            (AxisRotation::Deg270, X) => (Y, value),
+            (AxisRotation::Deg270, Y) => (X, -value),
+            _ => (code, value),
+        }
+    }
+}
+
+impl TryFrom<u16> for AxisRotation {
+    type Error = String;
+
+    fn try_from(degrees: u16) -> Result<Self, Self::Error> {
+        match degrees {
+            0 => Ok(AxisRotation::Deg0),
+            90 => Ok(AxisRotation::Deg90),
+            180 => Ok(AxisRotation::Deg180),
+            270 => Ok(AxisRotation::Deg270),
+            _ => Err(format!(
+                "Unsupported rotation {}, expected one of 0, 90, 180 or 270",
+                degrees
+            )),
+        }
+    }
+}
+
+impl From<AxisRotation> for u16 {
+    fn from(rotation: AxisRotation) -> Self {
+        match rotation {
+            AxisRotation::Deg0 => 0,
+            AxisRotation::Deg90 => 90,
+            AxisRotation::Deg180 => 180,
+            AxisRotation::Deg270 => 270,
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+into_pyobject_as!(AxisRotation, u16);
+
+#[cfg_attr(feature = "python", pymethods)]
 /// Represents a device description containing metadata and input configuration.
 ///
 /// This struct holds information about a device including its name, author, creation date,
@@ -135,6 +700,19 @@ fn default_device_name() -> String {
 /// * `axes` - Collection of axis input items
 /// * `buttons` - Collection of button input items  
 /// * `hats` - Collection of hat/directional pad input items
+/// * `rotation` - Mounting rotation in degrees (0, 90, 180 or 270)
+/// * `members` - Physical device names of a merged description
+/// * `normalize_range` - The `(min, max)` range analog axes are normalized to
+/// * `raw_hats` - Whether hat values are kept unclamped
+/// * `uniq` - Unique ID identifying one of several identical devices
+/// * `phys` - Physical path identifying one of several identical devices
+/// * `vendor_id` - USB vendor ID of the device
+/// * `product_id` - USB product ID of the device
+/// * `ignore` - Input codes the device pool drops
+/// * `grab` - Whether the device is grabbed for exclusive access
+/// * `match_mode` - How the device name is matched ("exact", "trimmed",
+///   "case_insensitive" or "substring")
+/// * `event_times` - Whether the kernel timestamps of input events are kept
 ///
 /// # Examples
 /// ```rust
@@ -145,8 +723,30 @@ fn default_device_name() -> String {
 /// let state = device.build_state();
 /// ```
 impl DeviceDescription {
+    #[cfg(feature = "python")]
     #[new]
-    fn new(
+    #[pyo3(signature = (
+        device_name = None,
+        author = None,
+        created = None,
+        description = None,
+        axes = None,
+        buttons = None,
+        hats = None,
+        rotation = 0,
+        normalize_range = DEFAULT_NORMALIZE_RANGE,
+        raw_hats = false,
+        uniq = None,
+        phys = None,
+        vendor_id = None,
+        product_id = None,
+        ignore = None,
+        grab = false,
+        match_mode = "trimmed",
+        event_times = false,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
         device_name: Option<String>,
         author: Option<String>,
         created: Option<String>,
@@ -154,47 +754,224 @@ impl DeviceDescription {
         axes: Option<Vec<DeviceItem>>,
         buttons: Option<Vec<DeviceItem>>,
         hats: Option<Vec<DeviceItem>>,
-    ) -> Self {
-        Self {
-            device_name: device_name.unwrap_or_else(default_device_name),
-            author,
-            created,
-            description,
-            axes: axes.unwrap_or_default(),
-            buttons: buttons.unwrap_or_default(),
-            hats: hats.unwrap_or_default(),
-        }
+        rotation: u16,
+        normalize_range: (f32, f32),
+        raw_hats: bool,
+        uniq: Option<String>,
+        phys: Option<String>,
+        vendor_id: Option<u16>,
+        product_id: Option<u16>,
+        ignore: Option<Vec<u16>>,
+        grab: bool,
+        match_mode: &str,
+        event_times: bool,
+    ) -> PyResult<Self> {
+        let rotation = AxisRotation::try_from(rotation)
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        let match_mode = NameMatch::try_from(match_mode.to_string())
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        Ok(Self {
+            rotation,
+            normalize_range,
+            raw_hats,
+            uniq,
+            phys,
+            vendor_id,
+            product_id,
+            ignore: ignore.unwrap_or_default(),
+            grab,
+            match_mode,
+            event_times,
+            ..Self::new(
+                device_name,
+                author,
+                created,
+                description,
+                axes,
+                buttons,
+                hats,
+            )
+        })
     }
 
     /// Create a DeviceDescription instance from a TOML file.
     ///
     /// # Arguments
-    /// * `toml_file` - Path to the TOML file containing device configuration
+    /// * `toml_file` - Path to the TOML file containing device configuration, as a
+    ///   string or `os.PathLike`
     ///
     /// # Returns
     /// DeviceDescription instance with axes, buttons, and hats populated
+    #[cfg(feature = "python")]
     #[staticmethod]
-    pub fn from_toml(toml_file: &str) -> PyResult<Self> {
+    pub fn from_toml(toml_file: PathBuf) -> PyResult<Self> {
         let content = fs::read_to_string(toml_file)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
-        let device: DeviceDescription = toml::from_str(&content)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
-        Ok(device)
+        Self::from_toml_str(&content)
+    }
+
+    /// Create a DeviceDescription instance from a TOML string.
+    ///
+    /// # Arguments
+    /// * `content` - TOML document containing the device configuration
+    ///
+    /// # Returns
+    /// DeviceDescription instance with axes, buttons, and hats populated
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    pub fn from_toml_str(content: &str) -> PyResult<Self> {
+        Self::from_toml_str_rust(content)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Create a DeviceDescription instance from the raw bytes of a TOML document.
+    ///
+    /// # Arguments
+    /// * `content` - UTF-8 encoded TOML document containing the device configuration
+    ///
+    /// # Returns
+    /// DeviceDescription instance with axes, buttons, and hats populated
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    pub fn from_toml_bytes(content: &[u8]) -> PyResult<Self> {
+        Self::from_toml_bytes_rust(content)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Create a DeviceDescription instance from an SDL `gamecontrollerdb.txt` line.
+    ///
+    /// # Arguments
+    /// * `line` - The SDL mapping line
+    /// * `device` - A description listing the inputs of the mapped device, see
+    ///   [`DeviceDescription::from_sdl_mapping_rust`]
+    ///
+    /// # Returns
+    /// DeviceDescription instance with the SDL names as aliases
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    #[pyo3(signature = (line, device = None))]
+    pub fn from_sdl_mapping(line: &str, device: Option<DeviceDescription>) -> PyResult<Self> {
+        Self::from_sdl_mapping_rust(line, device.as_ref())
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
+    }
+
+    /// Create a DeviceDescription instance from a JSON string.
+    ///
+    /// # Arguments
+    /// * `content` - JSON document containing the device configuration
+    ///
+    /// # Returns
+    /// DeviceDescription instance with axes, buttons, and hats populated
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    pub fn from_json_str(content: &str) -> PyResult<Self> {
+        Self::from_json_str_rust(content)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Create a DeviceDescription instance from a JSON file.
+    ///
+    /// # Arguments
+    /// * `json_file` - Path to the JSON file containing device configuration
+    ///
+    /// # Returns
+    /// DeviceDescription instance with axes, buttons, and hats populated
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    pub fn from_json(json_file: &str) -> PyResult<Self> {
+        let content = fs::read_to_string(json_file)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+        Self::from_json_str(&content)
+    }
+
+    /// Create a DeviceDescription instance from a TOML or JSON file.
+    ///
+    /// Files ending in `.json` are read as JSON, every other file as TOML.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the file containing device configuration
+    ///
+    /// # Returns
+    /// DeviceDescription instance with axes, buttons, and hats populated
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    pub fn from_file(path: &str) -> PyResult<Self> {
+        if is_json_path(path) {
+            Self::from_json(path)
+        } else {
+            Self::from_toml(PathBuf::from(path))
+        }
+    }
+
+    /// Create a DeviceDescription skeleton from a connected device.
+    ///
+    /// Every axis, button and hat the device supports is listed without an alias,
+    /// ready to be annotated and saved with `save_toml`.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the input device (e.g., "/dev/input/event0")
+    ///
+    /// # Returns
+    /// DeviceDescription named after the device with its detected inputs
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    pub fn from_device(path: &str) -> PyResult<Self> {
+        Self::from_device_rust(path)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))
+    }
+
+    /// Serialize the description to a TOML string.
+    ///
+    /// Fields that are not set are left out, so the output reads like a
+    /// hand-written description file.
+    #[cfg(feature = "python")]
+    pub fn to_toml(&self) -> PyResult<String> {
+        self.to_toml_rust()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Write the description to a TOML file.
+    ///
+    /// # Arguments
+    /// * `path` - Path of the TOML file to create or overwrite
+    #[cfg(feature = "python")]
+    pub fn save_toml(&self, path: &str) -> PyResult<()> {
+        self.save_toml_rust(path)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))
+    }
+
+    /// Serialize the description to a pretty-printed JSON string.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("device descriptions always serialize to JSON")
     }
 
     /// Build a state dictionary from the device description.
     ///
+    /// Axes start at their `initial` value (0.0 unless declared), buttons and hats at 0.
+    ///
     /// # Returns
     /// A HashMap with device state organized by type
     pub fn build_state(&self) -> JoystickState {
         let mut input_data = JoystickState::new();
 
         for axis in &self.axes {
-            input_data.axes.insert(axis.code, 0.0);
+            input_data
+                .axes
+                .insert(axis.code, axis.initial.unwrap_or(0.0));
+            if axis.track_crossings {
+                // Crossings are recorded on the logical axis, like the axis values
+                let (code, _) = self.rotation.apply(axis.code, 0.0);
+                input_data.axis_crossings.insert(code, 0);
+            }
+            if let Some(button) = axis.button_code {
+                input_data.buttons.insert(button, 0);
+                input_data.held.insert(button, 0);
+            }
         }
 
         for button in &self.buttons {
             input_data.buttons.insert(button.code, 0);
+            input_data.held.insert(button.code, 0);
         }
 
         for hat in &self.hats {
@@ -203,14 +980,473 @@ impl DeviceDescription {
 
         input_data
     }
+
+    /// Returns the code of the first axis declared with `alias`.
+    pub fn axis_code(&self, alias: &str) -> Option<u16> {
+        find_code(&self.axes, alias)
+    }
+
+    /// Returns the alias of the axis with `code`, if it has one.
+    pub fn axis_alias(&self, code: u16) -> Option<String> {
+        find_alias(&self.axes, code)
+    }
+
+    /// Returns the code of the first button declared with `alias`.
+    pub fn button_code(&self, alias: &str) -> Option<u16> {
+        find_code(&self.buttons, alias)
+    }
+
+    /// Returns the alias of the button with `code`, if it has one.
+    pub fn button_alias(&self, code: u16) -> Option<String> {
+        find_alias(&self.buttons, code)
+    }
+
+    /// Returns the code of the first hat declared with `alias`.
+    pub fn hat_code(&self, alias: &str) -> Option<u16> {
+        find_code(&self.hats, alias)
+    }
+
+    /// Returns the alias of the hat with `code`, if it has one.
+    pub fn hat_alias(&self, code: u16) -> Option<String> {
+        find_alias(&self.hats, code)
+    }
+
+    /// Flatten a state into a fixed-layout numeric vector.
+    ///
+    /// The layout follows the declaration order of the description: first every
+    /// axis (normalized value), then every button (0.0 or 1.0), then every hat
+    /// (-1.0, 0.0 or 1.0). Inputs missing from the state are written as 0.0, so the
+    /// vector always has `axes.len() + buttons.len() + hats.len()` entries.
+    ///
+    /// # Arguments
+    /// * `state` - The joystick state to flatten
+    ///
+    /// # Returns
+    /// The flattened state, suitable for `numpy.asarray`
+    pub fn state_to_vector(&self, state: &JoystickState) -> Vec<f32> {
+        let axes = self
+            .axes
+            .iter()
+            .map(|axis| state.axes.get(&axis.code).copied().unwrap_or(0.0));
+        let buttons = self
+            .buttons
+            .iter()
+            .map(|button| state.buttons.get(&button.code).copied().unwrap_or(0) as f32);
+        let hats = self
+            .hats
+            .iter()
+            .map(|hat| state.hats.get(&hat.code).copied().unwrap_or(0) as f32);
+
+        axes.chain(buttons).chain(hats).collect()
+    }
+
+    /// Rebuild a state from a vector produced by `state_to_vector`.
+    ///
+    /// Buttons are considered pressed for values >= 0.5, hats are rounded to the
+    /// nearest integer.
+    ///
+    /// # Arguments
+    /// * `vector` - The flattened state, in the layout described by `state_to_vector`
+    ///
+    /// # Returns
+    /// The reconstructed JoystickState
+    #[cfg(feature = "python")]
+    pub fn vector_to_state(&self, vector: Vec<f32>) -> PyResult<JoystickState> {
+        self.vector_to_state_rust(&vector)
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
+    }
+
+    /// Combine this description with `other` into one logical device.
+    ///
+    /// # Returns
+    /// The merged description, see `merge_rust`
+    #[cfg(feature = "python")]
+    pub fn merge(&self, other: &Self) -> PyResult<Self> {
+        self.merge_rust(other)
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
+    }
 }
 
 impl DeviceDescription {
+    /// Creates a new `DeviceDescription` with no rotation applied.
+    pub fn new(
+        device_name: Option<String>,
+        author: Option<String>,
+        created: Option<String>,
+        description: Option<String>,
+        axes: Option<Vec<DeviceItem>>,
+        buttons: Option<Vec<DeviceItem>>,
+        hats: Option<Vec<DeviceItem>>,
+    ) -> Self {
+        Self {
+            device_name: device_name.unwrap_or_else(default_device_name),
+            author,
+            created,
+            description,
+            axes: axes.unwrap_or_default(),
+            buttons: buttons.unwrap_or_default(),
+            hats: hats.unwrap_or_default(),
+            rotation: AxisRotation::default(),
+            members: Vec::new(),
+            normalize_range: DEFAULT_NORMALIZE_RANGE,
+            raw_hats: false,
+            uniq: None,
+            phys: None,
+            vendor_id: None,
+            product_id: None,
+            ignore: Vec::new(),
+            grab: false,
+            match_mode: NameMatch::default(),
+            event_times: false,
+        }
+        .with_resolved_hat_axes()
+    }
+
+    /// Resolves the `axis` of every hat item, see `DeviceItem::resolve_hat_axis`.
+    fn with_resolved_hat_axes(mut self) -> Self {
+        for hat in &mut self.hats {
+            hat.resolve_hat_axis();
+        }
+        self
+    }
+
+    /// Returns the names of the physical devices this description reads from.
+    ///
+    /// That is `members` for a merged description and the device name otherwise.
+    pub fn physical_names(&self) -> Vec<String> {
+        if self.members.is_empty() {
+            vec![self.device_name.clone()]
+        } else {
+            self.members.clone()
+        }
+    }
+
+    /// Returns true if the description declares a `uniq` or `phys` identity.
+    pub fn has_identity(&self) -> bool {
+        self.uniq.is_some() || self.phys.is_some()
+    }
+
+    /// Returns true if the description declares a USB `vendor_id` or `product_id`.
+    pub fn has_usb_ids(&self) -> bool {
+        self.vendor_id.is_some() || self.product_id.is_some()
+    }
+
+    /// Returns true if `device_name` is the name of one of the physical devices this
+    /// description reads from, compared according to `match_mode`.
+    pub fn matches_name(&self, device_name: &str) -> bool {
+        self.physical_names()
+            .iter()
+            .any(|name| self.match_mode.matches(name, device_name))
+    }
+
+    /// Returns true if `device` is one of the physical devices this description reads from.
+    pub fn matches(&self, device: &JoystickInfo) -> bool {
+        self.match_rank(device).is_some()
+    }
+
+    /// Returns how specifically this description matches `device`, `None` if it does not.
+    ///
+    /// A description with a `uniq` or `phys` identity matches the device reporting all
+    /// of the declared values (and USB IDs, if declared), whatever its name, so identical
+    /// devices can be told apart and named freely (rank 2). Otherwise a description
+    /// matches a device with the declared USB IDs (rank 1) and falls back to matching
+    /// by name (rank 0).
+    pub fn match_rank(&self, device: &JoystickInfo) -> Option<u8> {
+        fn same<T: PartialEq>(expected: &Option<T>, actual: &T) -> bool {
+            expected.as_ref().is_none_or(|expected| expected == actual)
+        }
+        let usb_ids_match =
+            same(&self.vendor_id, &device.vendor_id) && same(&self.product_id, &device.product_id);
+
+        if self.has_identity() {
+            let identity_matches = self
+                .uniq
+                .as_ref()
+                .is_none_or(|uniq| device.uniq.as_ref() == Some(uniq))
+                && self
+                    .phys
+                    .as_ref()
+                    .is_none_or(|phys| device.phys.as_ref() == Some(phys));
+            return (identity_matches && usb_ids_match).then_some(2);
+        }
+        if self.has_usb_ids() && usb_ids_match {
+            return Some(1);
+        }
+        self.matches_name(&device.name).then_some(0)
+    }
+
+    /// Removes the axes, buttons and hats listed in `ignore` from `state`.
+    ///
+    /// The raw axis values and button levels of the ignored codes are removed as well.
+    pub fn strip_ignored(&self, state: &mut JoystickState) {
+        if self.ignore.is_empty() {
+            return;
+        }
+        for code in &self.ignore {
+            state.axes.remove(code);
+            state.raw_axes.remove(code);
+            state.buttons.remove(code);
+            state.held.remove(code);
+            state.pressed_this_cycle.remove(code);
+            state.hats.remove(code);
+        }
+    }
+
+    /// Returns the described axis, button and hat codes missing from `capabilities`.
+    ///
+    /// `capabilities` is a capability map as returned by `JoystickLayout::capabilities`;
+    /// axes are looked up under `"axes"` and `"rel_axes"`, buttons under `"buttons"` and
+    /// hats under `"hats"`. Inputs listed in `ignore` are never reported.
+    ///
+    /// # Returns
+    /// The missing codes in ascending order, without duplicates.
+    pub fn missing_codes(&self, capabilities: &HashMap<String, Vec<u16>>) -> Vec<u16> {
+        let present = |kinds: &[&str], code: u16| {
+            kinds.iter().any(|kind| {
+                capabilities
+                    .get(*kind)
+                    .is_some_and(|codes| codes.contains(&code))
+            })
+        };
+
+        let mut missing: Vec<u16> = self
+            .axes
+            .iter()
+            .filter(|item| !present(&["axes", "rel_axes"], item.code))
+            .chain(
+                self.buttons
+                    .iter()
+                    .filter(|item| !present(&["buttons"], item.code)),
+            )
+            .chain(
+                self.hats
+                    .iter()
+                    .filter(|item| !present(&["hats"], item.code)),
+            )
+            .map(|item| item.code)
+            .filter(|code| !self.ignore.contains(code))
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        missing
+    }
+
+    /// Combines this description with `other` into one logical device.
+    ///
+    /// The merged description keeps the `device_name` of `self`, so its state shows up
+    /// under that name in the input register, and lists the physical devices of both
+    /// descriptions in `members`. Metadata missing from `self` is taken from `other`.
+    /// When only one of the descriptions is rotated, its rotation is kept, and the
+    /// ignored codes of both descriptions are dropped. Members are grabbed if either
+    /// description sets `grab`, and keep event times if either sets `event_times`.
+    ///
+    /// # Errors
+    /// Returns an error if both descriptions declare the same axis, button or hat code,
+    /// if they declare different non-zero rotations, different normalize ranges or
+    /// different `raw_hats` settings, or if either description is matched by identity or
+    /// USB IDs.
+    pub fn merge_rust(&self, other: &Self) -> Result<Self, String> {
+        fn combine(
+            kind: &str,
+            own: &[DeviceItem],
+            other: &[DeviceItem],
+        ) -> Result<Vec<DeviceItem>, String> {
+            if let Some(item) = other
+                .iter()
+                .find(|item| own.iter().any(|own| own.code == item.code))
+            {
+                return Err(format!(
+                    "{} code {} is declared by both devices",
+                    kind, item.code
+                ));
+            }
+            Ok(own.iter().chain(other).cloned().collect())
+        }
+
+        let rotation = match (self.rotation, other.rotation) {
+            (AxisRotation::Deg0, rotation) => rotation,
+            (rotation, AxisRotation::Deg0) => rotation,
+            (own, theirs) if own == theirs => own,
+            (own, theirs) => {
+                return Err(format!(
+                    "Cannot merge rotations {} and {}",
+                    u16::from(own),
+                    u16::from(theirs)
+                ))
+            }
+        };
+
+        if self.normalize_range != other.normalize_range {
+            return Err(format!(
+                "Cannot merge normalize ranges {:?} and {:?}",
+                self.normalize_range, other.normalize_range
+            ));
+        }
+
+        if self.raw_hats != other.raw_hats {
+            return Err("Cannot merge raw and clamped hats".to_string());
+        }
+
+        if self.match_mode != other.match_mode {
+            return Err("Cannot merge descriptions with different match modes".to_string());
+        }
+
+        // Members are matched by name, an identity would be lost in the merge
+        if [self, other]
+            .iter()
+            .any(|desc| desc.has_identity() || desc.has_usb_ids())
+        {
+            return Err("Cannot merge descriptions matched by uniq, phys or USB IDs".to_string());
+        }
+
+        let mut members = self.physical_names();
+        members.extend(other.physical_names());
+
+        Ok(Self {
+            device_name: self.device_name.clone(),
+            author: self.author.clone().or_else(|| other.author.clone()),
+            created: self.created.clone().or_else(|| other.created.clone()),
+            description: self
+                .description
+                .clone()
+                .or_else(|| other.description.clone()),
+            axes: combine("Axis", &self.axes, &other.axes)?,
+            buttons: combine("Button", &self.buttons, &other.buttons)?,
+            hats: combine("Hat", &self.hats, &other.hats)?,
+            rotation,
+            members,
+            normalize_range: self.normalize_range,
+            raw_hats: self.raw_hats,
+            uniq: None,
+            phys: None,
+            vendor_id: None,
+            product_id: None,
+            ignore: self
+                .ignore
+                .iter()
+                .chain(
+                    other
+                        .ignore
+                        .iter()
+                        .filter(|code| !self.ignore.contains(code)),
+                )
+                .copied()
+                .collect(),
+            grab: self.grab || other.grab,
+            match_mode: self.match_mode,
+            event_times: self.event_times || other.event_times,
+        })
+    }
+
+    /// Collects the aliases of all items that declare one.
+    pub fn aliases(&self) -> InputAliases {
+        fn collect(items: &[DeviceItem]) -> HashMap<u16, String> {
+            items
+                .iter()
+                .filter_map(|item| Some((item.code, item.alias.clone()?)))
+                .collect()
+        }
+
+        InputAliases {
+            axes: collect(&self.axes),
+            buttons: collect(&self.buttons),
+            hats: collect(&self.hats),
+        }
+    }
+
+    /// Rebuild a state from a vector produced by `state_to_vector` (Rust-only version).
+    pub fn vector_to_state_rust(&self, vector: &[f32]) -> Result<JoystickState, String> {
+        let expected = self.axes.len() + self.buttons.len() + self.hats.len();
+        if vector.len() != expected {
+            return Err(format!(
+                "Expected a vector of length {}, got {}",
+                expected,
+                vector.len()
+            ));
+        }
+
+        let (axis_values, rest) = vector.split_at(self.axes.len());
+        let (button_values, hat_values) = rest.split_at(self.buttons.len());
+        let mut state = JoystickState::new();
+
+        for (axis, &value) in self.axes.iter().zip(axis_values) {
+            state.axes.insert(axis.code, value);
+        }
+        for (button, &value) in self.buttons.iter().zip(button_values) {
+            let level = u8::from(value >= 0.5);
+            state.buttons.insert(button.code, level);
+            state.held.insert(button.code, level);
+        }
+        for (hat, &value) in self.hats.iter().zip(hat_values) {
+            state
+                .hats
+                .insert(hat.code, value.round().clamp(-1.0, 1.0) as i8);
+        }
+
+        Ok(state)
+    }
+
     /// Create a DeviceDescription instance from a TOML file (Rust-only version).
     pub fn from_toml_rust(toml_file: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        let content = fs::read_to_string(toml_file)?;
-        let device: DeviceDescription = toml::from_str(&content)?;
-        Ok(device)
+        Self::from_toml_path_rust(Path::new(toml_file))
+    }
+
+    /// Create a DeviceDescription instance from a TOML file given as a path (Rust-only version).
+    pub fn from_toml_path_rust(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path)?;
+        Self::from_toml_str_rust(&content)
+    }
+
+    /// Create a DeviceDescription instance from the bytes of a TOML document (Rust-only version).
+    ///
+    /// Fails if `bytes` is not valid UTF-8.
+    pub fn from_toml_bytes_rust(bytes: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_toml_str_rust(std::str::from_utf8(bytes)?)
+    }
+
+    /// Create a DeviceDescription instance from a TOML string (Rust-only version).
+    pub fn from_toml_str_rust(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let device: DeviceDescription = toml::from_str(content)?;
+        Ok(device.with_resolved_hat_axes())
+    }
+
+    /// Create a DeviceDescription skeleton from a connected device (Rust-only version).
+    pub fn from_device_rust(path: &str) -> Result<Self, std::io::Error> {
+        let joystick = Joystick::new(path)?;
+        Ok(joystick.layout().to_description(joystick.name()))
+    }
+
+    /// Serialize the description to a TOML string (Rust-only version).
+    pub fn to_toml_rust(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(self)
+    }
+
+    /// Write the description to a TOML file (Rust-only version).
+    pub fn save_toml_rust(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(path, self.to_toml_rust()?)?;
+        Ok(())
+    }
+
+    /// Create a DeviceDescription instance from a JSON file (Rust-only version).
+    pub fn from_json_rust(json_file: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(json_file)?;
+        Self::from_json_str_rust(&content)
+    }
+
+    /// Create a DeviceDescription instance from a TOML or JSON file, chosen by
+    /// extension (Rust-only version).
+    pub fn from_file_rust(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        if is_json_path(path) {
+            Self::from_json_rust(path)
+        } else {
+            Self::from_toml_rust(path)
+        }
+    }
+
+    /// Create a DeviceDescription instance from a JSON string (Rust-only version).
+    pub fn from_json_str_rust(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let device: DeviceDescription = serde_json::from_str(content)?;
+        Ok(device.with_resolved_hat_axes())
     }
 }
 
@@ -404,4 +1640,680 @@ invalid toml content
         assert_eq!(desc.axes.len(), deserialized.axes.len());
         assert_eq!(desc.axes[0].code, deserialized.axes[0].code);
     }
+
+    #[test]
+    fn test_rotation_defaults_to_zero() {
+        let desc: DeviceDescription = toml::from_str("device_name = \"Stick\"").unwrap();
+        assert_eq!(desc.rotation, AxisRotation::Deg0);
+    }
+
+    #[test]
+    fn test_rotation_from_toml() {
+        let desc: DeviceDescription = toml::from_str("rotation = 90").unwrap();
+        assert_eq!(desc.rotation, AxisRotation::Deg90);
+
+        let serialized = toml::to_string(&desc).unwrap();
+        assert!(serialized.contains("rotation = 90"));
+    }
+
+    #[test]
+    fn test_rotation_invalid_degrees() {
+        let result: Result<DeviceDescription, _> = toml::from_str("rotation = 45");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rotation_apply() {
+        let x = evdev::AbsoluteAxisCode::ABS_X.0;
+        let y = evdev::AbsoluteAxisCode::ABS_Y.0;
+
+        assert_eq!(AxisRotation::Deg0.apply(x, 0.5), (x, 0.5));
+        assert_eq!(AxisRotation::Deg0.apply(y, 0.5), (y, 0.5));
+
+        assert_eq!(AxisRotation::Deg90.apply(x, 0.5), (y, -0.5));
+        assert_eq!(AxisRotation::Deg90.apply(y, 0.5), (x, 0.5));
+
+        assert_eq!(AxisRotation::Deg180.apply(x, 0.5), (x, -0.5));
+        assert_eq!(AxisRotation::Deg180.apply(y, 0.5), (y, -0.5));
+
+        assert_eq!(AxisRotation::Deg270.apply(x, 0.5), (y, 0.5));
+        assert_eq!(AxisRotation::Deg270.apply(y, 0.5), (x, -0.5));
+    }
+
+    #[test]
+    fn test_rotation_ignores_other_axes() {
+        let rz = evdev::AbsoluteAxisCode::ABS_RZ.0;
+        for rotation in [
+            AxisRotation::Deg0,
+            AxisRotation::Deg90,
+            AxisRotation::Deg180,
+            AxisRotation::Deg270,
+        ] {
+            assert_eq!(rotation.apply(rz, 0.25), (rz, 0.25));
+        }
+    }
+
+    #[test]
+    fn test_from_toml_str_rust_valid() {
+        let toml_content = r#"
+device_name = "Inline Stick"
+
+[[axes]]
+code = 0
+alias = "X"
+
+[[buttons]]
+code = 288
+"#;
+
+        let desc = DeviceDescription::from_toml_str_rust(toml_content).unwrap();
+        assert_eq!(desc.device_name, "Inline Stick");
+        assert_eq!(desc.axes.len(), 1);
+        assert_eq!(desc.buttons.len(), 1);
+        assert_eq!(desc.buttons[0].code, 288);
+        assert_eq!(desc.buttons[0].alias, None);
+    }
+
+    #[test]
+    fn test_from_toml_str_rust_invalid() {
+        let result = DeviceDescription::from_toml_str_rust("device_name = ");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_json_str_rust_valid() {
+        let json_content = r#"{
+            "device_name": "Inline Stick",
+            "axes": [{"code": 0, "alias": "X"}, {"code": 1}],
+            "hats": [{"code": 16, "alias": "ABS_HAT0X"}],
+            "rotation": 180
+        }"#;
+
+        let desc = DeviceDescription::from_json_str_rust(json_content).unwrap();
+        assert_eq!(desc.device_name, "Inline Stick");
+        assert_eq!(desc.axes.len(), 2);
+        assert_eq!(desc.axes[1].alias, None);
+        assert!(desc.buttons.is_empty());
+        assert_eq!(desc.hats[0].code, 16);
+        assert_eq!(desc.rotation, AxisRotation::Deg180);
+    }
+
+    #[test]
+    fn test_from_json_str_rust_invalid() {
+        let result = DeviceDescription::from_json_str_rust(r#"{"device_name": "#);
+        assert!(result.is_err());
+    }
+
+    fn vector_test_description() -> DeviceDescription {
+        DeviceDescription::new(
+            None,
+            None,
+            None,
+            None,
+            Some(vec![DeviceItem::new(1, None), DeviceItem::new(0, None)]),
+            Some(vec![DeviceItem::new(288, None), DeviceItem::new(289, None)]),
+            Some(vec![DeviceItem::new(16, None)]),
+        )
+    }
+
+    #[test]
+    fn test_state_to_vector_layout() {
+        let desc = vector_test_description();
+        let mut state = desc.build_state();
+        state.axes.insert(0, 0.25);
+        state.axes.insert(1, -0.5);
+        state.buttons.insert(289, 1);
+        state.hats.insert(16, -1);
+
+        // Declared order: axes [1, 0], buttons [288, 289], hats [16]
+        assert_eq!(
+            desc.state_to_vector(&state),
+            vec![-0.5, 0.25, 0.0, 1.0, -1.0]
+        );
+    }
+
+    #[test]
+    fn test_state_to_vector_missing_inputs_are_zero() {
+        let desc = vector_test_description();
+        let vector = desc.state_to_vector(&JoystickState::new());
+        assert_eq!(vector, vec![0.0; 5]);
+    }
+
+    #[test]
+    fn test_vector_round_trip() {
+        let desc = vector_test_description();
+        let mut state = desc.build_state();
+        state.axes.insert(1, 0.75);
+        state.buttons.insert(288, 1);
+        state.held.insert(288, 1);
+        state.hats.insert(16, 1);
+
+        let vector = desc.state_to_vector(&state);
+        let restored = desc.vector_to_state_rust(&vector).unwrap();
+        assert_eq!(restored, state);
+    }
+
+    #[test]
+    fn test_vector_to_state_length_mismatch() {
+        let desc = vector_test_description();
+        assert!(desc.vector_to_state_rust(&[0.0; 4]).is_err());
+        assert!(desc.vector_to_state_rust(&[0.0; 6]).is_err());
+    }
+
+    #[test]
+    fn test_invert_defaults_to_false() {
+        let item = DeviceItem::new(1, None);
+        assert!(!item.invert);
+
+        let desc = DeviceDescription::from_toml_str_rust("[[axes]]\ncode = 1").unwrap();
+        assert!(!desc.axes[0].invert);
+    }
+
+    #[test]
+    fn test_invert_toml_round_trip() {
+        let toml_content = r#"
+[[axes]]
+code = 1
+alias = "ABS_Y"
+invert = true
+"#;
+
+        let desc = DeviceDescription::from_toml_str_rust(toml_content).unwrap();
+        assert!(desc.axes[0].invert);
+
+        let serialized = toml::to_string(&desc).unwrap();
+        let deserialized = DeviceDescription::from_toml_str_rust(&serialized).unwrap();
+        assert!(deserialized.axes[0].invert);
+        assert_eq!(deserialized.axes[0].alias, Some("ABS_Y".to_string()));
+    }
+
+    #[test]
+    fn test_lookup_by_alias_and_code() {
+        let desc = DeviceDescription::from_toml_str_rust(
+            r#"
+device_name = "Stick"
+
+[[axes]]
+code = 0
+alias = "Roll"
+
+[[axes]]
+code = 1
+
+[[buttons]]
+code = 288
+alias = "Trigger"
+
+[[buttons]]
+code = 289
+alias = "Trigger"
+
+[[hats]]
+code = 16
+alias = "POV X"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(desc.axis_code("Roll"), Some(0));
+        assert_eq!(desc.axis_alias(0), Some("Roll".to_string()));
+        assert_eq!(desc.hat_code("POV X"), Some(16));
+        assert_eq!(desc.hat_alias(16), Some("POV X".to_string()));
+
+        // Misses
+        assert_eq!(desc.axis_code("Pitch"), None);
+        assert_eq!(desc.axis_alias(1), None);
+        assert_eq!(desc.button_alias(300), None);
+        assert_eq!(desc.button_code("Roll"), None);
+
+        // The first declaration of a duplicated alias wins
+        assert_eq!(desc.button_code("Trigger"), Some(288));
+        assert_eq!(desc.button_alias(289), Some("Trigger".to_string()));
+    }
+
+    fn temp_file_with(suffix: &str, content: &str) -> NamedTempFile {
+        let mut temp_file = tempfile::Builder::new().suffix(suffix).tempfile().unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
+        temp_file
+    }
+
+    #[test]
+    fn test_from_json_file() {
+        let temp_file = temp_file_with(
+            ".json",
+            r#"{"device_name": "Json Stick", "buttons": [{"code": 288, "alias": "Trigger"}]}"#,
+        );
+        let path = temp_file.path().to_str().unwrap();
+
+        let desc = DeviceDescription::from_json_rust(path).unwrap();
+        assert_eq!(desc.device_name, "Json Stick");
+        assert_eq!(desc.buttons[0].code, 288);
+        assert_eq!(desc.buttons[0].alias, Some("Trigger".to_string()));
+    }
+
+    #[test]
+    fn test_from_json_file_invalid() {
+        let temp_file = temp_file_with(".json", r#"{"device_name": "Broken", "axes": 3"#);
+        let path = temp_file.path().to_str().unwrap();
+
+        assert!(DeviceDescription::from_json_rust(path).is_err());
+        assert!(DeviceDescription::from_json_rust("/nonexistent/device.json").is_err());
+    }
+
+    #[test]
+    fn test_from_file_dispatches_by_extension() {
+        let json_file = temp_file_with(".JSON", r#"{"device_name": "Json Stick"}"#);
+        let toml_file = temp_file_with(".toml", "device_name = \"Toml Stick\"\n");
+
+        let json = DeviceDescription::from_file_rust(json_file.path().to_str().unwrap()).unwrap();
+        let toml = DeviceDescription::from_file_rust(toml_file.path().to_str().unwrap()).unwrap();
+        assert_eq!(json.device_name, "Json Stick");
+        assert_eq!(toml.device_name, "Toml Stick");
+
+        // A JSON document behind a TOML extension is parsed as TOML and fails
+        let mislabeled = temp_file_with(".toml", r#"{"device_name": "Json Stick"}"#);
+        assert!(DeviceDescription::from_file_rust(mislabeled.path().to_str().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_to_json_round_trip() {
+        let desc = DeviceDescription::from_toml_str_rust(
+            r#"
+device_name = "Stick"
+rotation = 90
+
+[[axes]]
+code = 0
+alias = "Roll"
+invert = true
+"#,
+        )
+        .unwrap();
+
+        let reloaded = DeviceDescription::from_json_str_rust(&desc.to_json()).unwrap();
+        assert_eq!(reloaded.device_name, "Stick");
+        assert_eq!(reloaded.rotation, AxisRotation::Deg90);
+        assert_eq!(reloaded.axes[0].alias, Some("Roll".to_string()));
+        assert!(reloaded.axes[0].invert);
+    }
+
+    #[test]
+    fn test_toml_save_round_trip() {
+        let source = temp_file_with(
+            ".toml",
+            r#"
+device_name = "Stick"
+author = "Test Author"
+rotation = 270
+
+[[axes]]
+code = 0
+alias = "Roll"
+
+[[axes]]
+code = 1
+invert = true
+
+[[buttons]]
+code = 288
+alias = "Trigger"
+
+[[hats]]
+code = 16
+"#,
+        );
+        let desc = DeviceDescription::from_toml_rust(source.path().to_str().unwrap()).unwrap();
+
+        let saved = NamedTempFile::new().unwrap();
+        let saved_path = saved.path().to_str().unwrap();
+        desc.save_toml_rust(saved_path).unwrap();
+        let reloaded = DeviceDescription::from_toml_rust(saved_path).unwrap();
+
+        assert_eq!(reloaded, desc);
+
+        // Unset optional fields are omitted
+        let output = fs::read_to_string(saved_path).unwrap();
+        assert!(output.contains("author"));
+        assert!(!output.contains("created"));
+        assert!(!output.contains("description"));
+        assert_eq!(output.matches("alias").count(), 2);
+    }
+
+    #[test]
+    fn test_merge_combines_inputs_and_members() {
+        let stick = DeviceDescription::from_toml_str_rust(
+            r#"
+            device_name = "Stick"
+            rotation = 90
+            axes = [{ code = 0, alias = "roll" }, { code = 1, alias = "pitch" }]
+            buttons = [{ code = 288, alias = "trigger" }]
+            "#,
+        )
+        .unwrap();
+        let throttle = DeviceDescription::from_toml_str_rust(
+            r#"
+            device_name = "Throttle"
+            author = "Pilot"
+            axes = [{ code = 2, alias = "throttle" }]
+            buttons = [{ code = 289 }]
+            hats = [{ code = 16 }, { code = 17 }]
+            "#,
+        )
+        .unwrap();
+
+        let hotas = stick.merge_rust(&throttle).unwrap();
+        assert_eq!(hotas.device_name, "Stick");
+        assert_eq!(hotas.members, vec!["Stick", "Throttle"]);
+        assert_eq!(hotas.author.as_deref(), Some("Pilot"));
+        assert_eq!(hotas.rotation, AxisRotation::Deg90);
+        assert_eq!(hotas.axis_code("throttle"), Some(2));
+
+        let state = hotas.build_state();
+        assert_eq!(state.axes.len(), 3);
+        assert_eq!(state.buttons.len(), 2);
+        assert_eq!(state.hats.len(), 2);
+
+        let parsed = DeviceDescription::from_toml_str_rust(&hotas.to_toml_rust().unwrap()).unwrap();
+        assert_eq!(parsed, hotas);
+    }
+
+    #[test]
+    fn test_merge_rejects_code_collisions() {
+        let stick = DeviceDescription::new(
+            Some("Stick".to_string()),
+            None,
+            None,
+            None,
+            None,
+            Some(vec![DeviceItem::new(288, None)]),
+            None,
+        );
+        let error = stick.merge_rust(&stick).unwrap_err();
+        assert!(error.contains("288"));
+    }
+
+    #[test]
+    fn test_normalize_range_defaults_and_parses() {
+        let description = DeviceDescription::from_toml_str_rust("device_name = \"Stick\"").unwrap();
+        assert_eq!(description.normalize_range, DEFAULT_NORMALIZE_RANGE);
+        assert!(!description
+            .to_toml_rust()
+            .unwrap()
+            .contains("normalize_range"));
+
+        let description =
+            DeviceDescription::from_toml_str_rust("normalize_range = [0.0, 127.0]").unwrap();
+        assert_eq!(description.normalize_range, (0.0, 127.0));
+        let parsed =
+            DeviceDescription::from_toml_str_rust(&description.to_toml_rust().unwrap()).unwrap();
+        assert_eq!(parsed, description);
+    }
+
+    #[test]
+    fn test_debounce_ms_parses_per_item() {
+        let description = DeviceDescription::from_toml_str_rust(
+            r#"
+            buttons = [{ code = 288, debounce_ms = 5 }, { code = 289 }]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(description.buttons[0].debounce_ms, Some(5));
+        assert_eq!(description.buttons[1].debounce_ms, None);
+        let parsed =
+            DeviceDescription::from_toml_str_rust(&description.to_toml_rust().unwrap()).unwrap();
+        assert_eq!(parsed, description);
+    }
+
+    #[test]
+    fn test_raw_hats_defaults_and_round_trips() {
+        let description = DeviceDescription::from_toml_str_rust("").unwrap();
+        assert!(!description.raw_hats);
+        assert!(!toml::to_string(&description).unwrap().contains("raw_hats"));
+
+        let description = DeviceDescription::from_toml_str_rust("raw_hats = true").unwrap();
+        assert!(description.raw_hats);
+        let serialized = toml::to_string(&description).unwrap();
+        assert!(
+            DeviceDescription::from_toml_str_rust(&serialized)
+                .unwrap()
+                .raw_hats
+        );
+
+        let clamped = DeviceDescription::from_toml_str_rust("").unwrap();
+        assert!(description.merge_rust(&clamped).is_err());
+    }
+
+    #[test]
+    fn test_from_toml_path() {
+        let temp_file = temp_file_with(".toml", "device_name = \"Path Stick\"\n");
+        let path: std::path::PathBuf = temp_file.path().to_path_buf();
+
+        let desc = DeviceDescription::from_toml_path_rust(&path).unwrap();
+        assert_eq!(desc.device_name, "Path Stick");
+        assert!(
+            DeviceDescription::from_toml_path_rust(Path::new("/nonexistent/device.toml")).is_err()
+        );
+    }
+
+    #[test]
+    fn test_from_toml_bytes() {
+        let content =
+            b"device_name = \"Bytes Stick\"\n\n[[buttons]]\ncode = 288\nalias = \"trigger\"\n";
+
+        let desc = DeviceDescription::from_toml_bytes_rust(content).unwrap();
+        assert_eq!(desc.device_name, "Bytes Stick");
+        assert_eq!(desc.buttons[0].code, 288);
+        assert!(DeviceDescription::from_toml_bytes_rust(&[0xff, 0xfe]).is_err());
+    }
+
+    #[test]
+    fn test_strip_ignored_removes_listed_codes() {
+        let description =
+            DeviceDescription::from_toml_str_rust("device_name = \"Stick\"\nignore = [40, 300]")
+                .unwrap();
+        assert_eq!(description.ignore, vec![40, 300]);
+
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.5);
+        state.axes.insert(40, 0.9);
+        state.raw_axes.insert(40, 900);
+        state.buttons.insert(300, 1);
+        state.held.insert(300, 1);
+        state.buttons.insert(288, 1);
+        description.strip_ignored(&mut state);
+
+        assert_eq!(state.axes, HashMap::from([(0, 0.5)]));
+        assert!(state.raw_axes.is_empty());
+        assert_eq!(state.buttons, HashMap::from([(288, 1)]));
+        assert!(state.held.is_empty());
+    }
+
+    #[test]
+    fn test_grab_defaults_and_round_trips() {
+        let description = DeviceDescription::from_toml_str_rust("device_name = \"Stick\"").unwrap();
+        assert!(!description.grab);
+        assert!(!toml::to_string(&description).unwrap().contains("grab"));
+
+        let description =
+            DeviceDescription::from_toml_str_rust("device_name = \"Stick\"\ngrab = true").unwrap();
+        assert!(description.grab);
+        let reloaded =
+            DeviceDescription::from_toml_str_rust(&description.to_toml_rust().unwrap()).unwrap();
+        assert!(reloaded.grab);
+    }
+
+    #[test]
+    fn test_quadratic_curve_halves_and_keeps_sign() {
+        let curve = AxisCurve::try_from("quadratic".to_string()).unwrap();
+        assert_eq!(curve.apply(0.5), 0.25);
+        assert_eq!(curve.apply(-0.5), -0.25);
+        assert_eq!(curve.apply(1.0), 1.0);
+        assert_eq!(curve.apply(0.0), 0.0);
+        assert_eq!(AxisCurve::Linear.apply(-0.5), -0.5);
+    }
+
+    #[test]
+    fn test_curve_parses_and_round_trips() {
+        let desc = DeviceDescription::from_toml_str_rust(
+            r#"
+[[axes]]
+code = 0
+curve = "power:3"
+
+[[axes]]
+code = 1
+curve = "quadratic"
+
+[[axes]]
+code = 2
+"#,
+        )
+        .unwrap();
+        assert_eq!(desc.axes[0].curve, AxisCurve::Power(3.0));
+        assert_eq!(desc.axes[1].curve, AxisCurve::Power(2.0));
+        assert_eq!(desc.axes[2].curve, AxisCurve::Linear);
+        assert_eq!(desc.axes[0].curve.apply(-0.5), -0.125);
+
+        let toml = desc.to_toml_rust().unwrap();
+        assert!(toml.contains("curve = \"power:3\""));
+        assert!(toml.contains("curve = \"quadratic\""));
+        assert_eq!(toml.matches("curve").count(), 2);
+        assert_eq!(DeviceDescription::from_toml_str_rust(&toml).unwrap(), desc);
+
+        for invalid in ["cubic", "power:", "power:0", "power:-2", "power:nan"] {
+            assert!(
+                AxisCurve::try_from(invalid.to_string()).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_name_match_modes() {
+        assert!(NameMatch::Exact.matches("T.16000M", "T.16000M"));
+        assert!(!NameMatch::Exact.matches("T.16000M", "T.16000M "));
+
+        assert!(NameMatch::Trimmed.matches("T.16000M", "  T.16000M \n"));
+        assert!(!NameMatch::Trimmed.matches("T.16000M", "t.16000m"));
+
+        assert!(NameMatch::CaseInsensitive.matches("T.16000M", " t.16000m"));
+        assert!(!NameMatch::CaseInsensitive.matches("T.16000M", "Thrustmaster T.16000M"));
+
+        assert!(NameMatch::Substring.matches("t.16000m", "Thrustmaster T.16000M "));
+        assert!(!NameMatch::Substring.matches("TWCS", "Thrustmaster T.16000M"));
+        assert!(!NameMatch::Substring.matches(" ", "Thrustmaster T.16000M"));
+    }
+
+    #[test]
+    fn test_match_mode_defaults_and_round_trips() {
+        let description = DeviceDescription::from_toml_str_rust("device_name = \"Stick\"").unwrap();
+        assert_eq!(description.match_mode, NameMatch::Trimmed);
+        assert!(!toml::to_string(&description)
+            .unwrap()
+            .contains("match_mode"));
+
+        let description = DeviceDescription::from_toml_str_rust(
+            "device_name = \"T.16000M\"\nmatch_mode = \"substring\"",
+        )
+        .unwrap();
+        assert_eq!(description.match_mode, NameMatch::Substring);
+        let reloaded =
+            DeviceDescription::from_toml_str_rust(&toml::to_string(&description).unwrap()).unwrap();
+        assert_eq!(reloaded.match_mode, NameMatch::Substring);
+
+        let device = JoystickInfo {
+            name: "Thrustmaster T.16000M".to_string(),
+            ..JoystickInfo::default()
+        };
+        assert_eq!(description.match_rank(&device), Some(0));
+
+        assert!(DeviceDescription::from_toml_str_rust("match_mode = \"fuzzy\"").is_err());
+    }
+
+    #[test]
+    fn test_build_state_uses_initial_axis_value() {
+        let desc = DeviceDescription::from_toml_str_rust(
+            r#"
+device_name = "Throttle"
+
+[[axes]]
+code = 2
+initial = -1.0
+
+[[axes]]
+code = 0
+"#,
+        )
+        .unwrap();
+        assert_eq!(desc.axes[0].initial, Some(-1.0));
+
+        let state = desc.build_state();
+        assert_eq!(state.axes[&2], -1.0);
+        assert_eq!(state.axes[&0], 0.0);
+
+        let toml = toml::to_string(&desc).unwrap();
+        assert_eq!(toml.matches("initial").count(), 1);
+    }
+
+    #[test]
+    fn test_missing_codes_lists_described_inputs_the_device_lacks() {
+        let desc = DeviceDescription::new(
+            Some("Stick".to_string()),
+            None,
+            None,
+            None,
+            Some(vec![DeviceItem::new(0, None), DeviceItem::new(8, None)]),
+            Some(vec![DeviceItem::new(288, None), DeviceItem::new(300, None)]),
+            Some(vec![DeviceItem::new(16, None)]),
+        );
+        let capabilities = HashMap::from([
+            ("axes".to_string(), vec![0, 1]),
+            ("buttons".to_string(), vec![288, 289]),
+            ("hats".to_string(), vec![16, 17]),
+            ("rel_axes".to_string(), vec![8]),
+        ]);
+
+        assert_eq!(desc.missing_codes(&capabilities), vec![300]);
+        assert_eq!(
+            desc.missing_codes(&HashMap::new()),
+            vec![0, 8, 16, 288, 300]
+        );
+    }
+
+    #[test]
+    fn test_hat_axis_aliases_resolve_to_x_and_y_codes() {
+        let desc = DeviceDescription::from_toml_str_rust(
+            r#"
+device_name = "Pad"
+
+[[hats]]
+code = 16
+axis = "x"
+alias = "dpad_left_right"
+
+[[hats]]
+code = 16
+axis = "y"
+alias = "dpad_up_down"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(desc.hat_code("dpad_left_right"), Some(16));
+        assert_eq!(desc.hat_code("dpad_up_down"), Some(17));
+        assert_eq!(desc.hat_alias(17), Some("dpad_up_down".to_string()));
+        let state = desc.build_state();
+        assert_eq!(state.hats, HashMap::from([(16, 0), (17, 0)]));
+
+        // The resolved codes survive a round trip
+        let reparsed =
+            DeviceDescription::from_toml_str_rust(&desc.to_toml_rust().unwrap()).unwrap();
+        assert_eq!(reparsed, desc);
+        // Codes of other hats keep their pair
+        let mut item = DeviceItem::new(19, None);
+        item.axis = Some(HatAxis::X);
+        let desc = DeviceDescription::new(None, None, None, None, None, None, Some(vec![item]));
+        assert_eq!(desc.hats[0].code, 18);
+    }
 }
@@ -1,12 +1,90 @@
-use crate::inner::description::DeviceDescription;
-use crate::inner::joystick::Joystick;
-use crate::utils::{fetch_connected_joysticks, JoystickState};
-use std::collections::HashMap;
+use crate::inner::description::{AxisRotation, DeviceDescription, DEFAULT_BUTTON_THRESHOLD};
+use crate::inner::joystick::{Joystick, JoystickEventStream};
+use crate::utils::{fetch_connected_joysticks, monotonic_us, JoystickInfo, JoystickState};
+use futures::FutureExt;
+use log::{error, info, warn};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use tokio::sync::mpsc;
+use tokio::runtime::Handle;
+use tokio::sync::{broadcast, mpsc, watch, Notify};
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 
+#[cfg(feature = "websocket")]
+mod ws;
+#[cfg(feature = "websocket")]
+pub use ws::WsServer;
+
+/// The flags, device list and monitor bookkeeping of a [`DevicePool`], shared with the
+/// pool and usable without locking it.
+///
+/// The Python wrapper keeps one next to its pool, so the quick status calls never wait
+/// for a pool lock held by a long `start()` or `fetch()` while they hold the GIL.
+#[cfg(feature = "python")]
+#[derive(Clone)]
+pub(crate) struct PoolStatus {
+    running: Arc<Mutex<bool>>,
+    paused: Arc<AtomicBool>,
+    input_register: Arc<Mutex<HashMap<String, JoystickState>>>,
+    live_monitors: Arc<Mutex<HashMap<String, usize>>>,
+    /// The described devices in description order, which only change at construction.
+    device_names: Vec<String>,
+}
+
+#[cfg(feature = "python")]
+impl PoolStatus {
+    /// See [`DevicePool::is_running`].
+    pub(crate) fn is_running(&self) -> bool {
+        *self.running.lock().unwrap()
+    }
+
+    /// See [`DevicePool::pause`].
+    pub(crate) fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// See [`DevicePool::resume`].
+    pub(crate) fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+    }
+
+    /// See [`DevicePool::is_paused`].
+    pub(crate) fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// See [`DevicePool::monitored_devices`].
+    pub(crate) fn monitored_devices(&self) -> Vec<String> {
+        let input_register = self.input_register.lock().unwrap();
+        self.device_names
+            .iter()
+            .filter(|device_name| input_register.contains_key(*device_name))
+            .cloned()
+            .collect()
+    }
+
+    /// See [`DevicePool::device_health`].
+    pub(crate) fn device_health(&self) -> HashMap<String, bool> {
+        device_health(&self.live_monitors)
+    }
+}
+
+/// Maps each device with a monitor task record to whether one of its tasks is alive.
+fn device_health(live_monitors: &Mutex<HashMap<String, usize>>) -> HashMap<String, bool> {
+    live_monitors
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .map(|(device_name, &count)| (device_name.clone(), count > 0))
+        .collect()
+}
+
 /// A pool for managing multiple input devices (joysticks/gamepads) with debouncing capabilities.
 ///
 /// The `DevicePool` manages a collection of input devices and provides centralized handling
@@ -19,18 +97,377 @@ use tokio::time::sleep;
 /// - Button debouncing to prevent accidental multiple triggers
 /// - Thread-safe operation with Arc<Mutex<>> for concurrent access
 /// - Graceful shutdown mechanism via message passing
+/// - Optional hotplug detection that resumes monitoring of reconnected devices
 ///
 /// # Thread Safety
 /// All shared state is protected by Arc<Mutex<>> to ensure safe concurrent access
-/// across multiple threads.
+/// across multiple threads. The locks are `std::sync::Mutex` on purpose: every critical
+/// section only copies or updates plain maps and never awaits, so a guard is never held
+/// across a scheduling point (clippy's `await_holding_lock` keeps it that way) and the
+/// pool stays usable from the synchronous Python wrappers. When locks are nested,
+/// `input_register` is always taken first.
+///
+/// Besides the registers, every change is appended to an event log read with
+/// [`DevicePool::events`], for consumers that prefer a flat stream of attributed events.
 pub struct DevicePool {
     debounce_time: Duration,
+    debounce_overrides: Arc<DebounceOverrides>,
+    axis_smoothing: Arc<AxisSmoothing>,
+    smoothing_targets: Arc<Mutex<SmoothingTargets>>,
+    axis_buttons: Arc<AxisButtons>,
     devices: Vec<DeviceDescription>,
     input_register: Arc<Mutex<HashMap<String, JoystickState>>>,
+    register_version: Arc<AtomicU64>,
+    register_changed: Arc<Notify>,
     last_input_register: Arc<Mutex<HashMap<String, JoystickState>>>,
-    last_button_time: Arc<Mutex<HashMap<u16, Instant>>>,
+    last_button_time: Arc<Mutex<HashMap<(String, u16), Instant>>>,
+    button_press_time: Arc<Mutex<HashMap<(String, u16), Instant>>>,
+    button_callbacks: Arc<Mutex<ButtonCallbacks>>,
+    device_status: Arc<Mutex<HashMap<String, DeviceStatus>>>,
     running: Arc<Mutex<bool>>,
-    shutdown_tx: Option<mpsc::Sender<()>>,
+    paused: Arc<AtomicBool>,
+    shutdown_tx: Option<watch::Sender<bool>>,
+    cancel_tx: Option<watch::Sender<bool>>,
+    supervisor: Option<JoinHandle<()>>,
+    hotplug: bool,
+    hotplug_tx: Option<mpsc::UnboundedSender<HotplugEvent>>,
+    load_errors: Vec<(String, String)>,
+    runtime: Option<Handle>,
+    max_open_retries: u32,
+    open_retry_delay: Duration,
+    change_epsilon: f32,
+    activity_tx: broadcast::Sender<String>,
+    event_log: Arc<Mutex<VecDeque<InputEvent>>>,
+    live_monitors: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+/// A callback fired with the device name and button code when a button is pressed.
+pub type ButtonCallback = Arc<dyn Fn(&str, u16) + Send + Sync>;
+
+/// Registered button callbacks keyed by device name and button code.
+type ButtonCallbacks = HashMap<(String, u16), Vec<ButtonCallback>>;
+
+/// A batch of button callback calls, run by the callback worker.
+type CallbackJob = Box<dyn FnOnce() + Send>;
+
+/// Per-code debounce times declared with `debounce_ms`, keyed by device name and code.
+type DebounceOverrides = HashMap<(String, u16), Duration>;
+
+/// Axis smoothing factors declared with `smoothing`, keyed by device name and axis code.
+type AxisSmoothing = HashMap<(String, u16), f32>;
+
+/// Smoothed axes still moving towards their latest reading, as `(factor, target)` keyed
+/// by device name and logical axis code.
+type SmoothingTargets = HashMap<(String, u16), (f32, f32)>;
+
+/// Synthetic buttons declared with `button_code` as `(button code, threshold)`, keyed by
+/// device name and axis code.
+type AxisButtons = HashMap<(String, u16), (u16, f32)>;
+
+/// The delay before the first retry of a failed device open, unless set otherwise.
+pub const DEFAULT_OPEN_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// How many activity notifications a subscriber can fall behind before it misses some.
+const ACTIVITY_CAPACITY: usize = 256;
+
+/// How many input events the pool keeps for [`DevicePool::events`] before dropping the oldest.
+const EVENT_LOG_CAPACITY: usize = 4096;
+
+/// How often a monitor moves its smoothed axes on towards their latest reading.
+const SMOOTHING_TICK: Duration = Duration::from_millis(10);
+
+/// How close a smoothed axis must get to its reading to snap onto it.
+const SMOOTHING_SETTLED: f32 = 1e-4;
+
+/// How often the hotplug watcher re-enumerates the connected devices.
+const HOTPLUG_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The longest the hotplug watcher waits before restarting a monitor that exited while
+/// its device stayed connected. The wait starts at `HOTPLUG_INTERVAL` and doubles.
+const MAX_RESPAWN_DELAY: Duration = Duration::from_secs(60);
+
+/// A change in the set of connected devices, reported by the hotplug watcher.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HotplugEvent {
+    /// A device matching a description appeared and is now monitored.
+    Connected(String),
+    /// A previously monitored device is no longer connected.
+    Disconnected(String),
+}
+
+/// The ways fetching from a [`DevicePool`] can fail.
+#[derive(Debug)]
+pub enum DevicePoolError {
+    /// Monitoring has not been started, call `reset()` first.
+    NotRunning,
+    /// No input changed before the timeout expired.
+    Timeout,
+    /// No description of the named device is loaded.
+    UnknownDevice(String),
+    /// The named described devices were still not connected when the timeout expired.
+    MissingDevices(Vec<String>),
+    /// Reading from a device failed.
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for DevicePoolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DevicePoolError::NotRunning => {
+                write!(f, "Device monitoring is not running. Call reset() first.")
+            }
+            DevicePoolError::Timeout => write!(f, "Fetch operation timed out"),
+            DevicePoolError::UnknownDevice(device_name) => {
+                write!(f, "No device named '{}' is loaded", device_name)
+            }
+            DevicePoolError::MissingDevices(missing) => {
+                write!(f, "Timed out waiting for devices: {}", missing.join(", "))
+            }
+            DevicePoolError::Io(e) => write!(f, "Device I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DevicePoolError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DevicePoolError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DevicePoolError {
+    fn from(e: std::io::Error) -> Self {
+        DevicePoolError::Io(e)
+    }
+}
+
+/// Runtime bookkeeping kept for every device that has a monitor task.
+#[derive(Debug, Clone, Default)]
+struct DeviceStatus {
+    monitored: bool,
+    last_input: Option<Instant>,
+    last_error: Option<String>,
+}
+
+/// A monitor task of the supervisor, tracked under the path of its device.
+///
+/// The entry stays after the task exits, so the hotplug watcher can tell a device
+/// that vanished from one that is still connected but lost its monitor.
+///
+/// # Fields
+///
+/// * `name` - The name the device reported
+/// * `task` - The monitor task
+/// * `restarts` - How often the monitor was restarted while the device stayed connected
+/// * `retry_at` - When to restart the exited monitor, set once its exit was noticed
+struct TrackedMonitor {
+    name: String,
+    task: JoinHandle<()>,
+    restarts: u32,
+    retry_at: Option<Instant>,
+}
+
+/// Shared state handed to every device monitor task.
+#[derive(Clone)]
+struct MonitorContext {
+    input_register: Arc<Mutex<HashMap<String, JoystickState>>>,
+    register_version: Arc<AtomicU64>,
+    register_changed: Arc<Notify>,
+    last_button_time: Arc<Mutex<HashMap<(String, u16), Instant>>>,
+    button_press_time: Arc<Mutex<HashMap<(String, u16), Instant>>>,
+    button_callbacks: Arc<Mutex<ButtonCallbacks>>,
+    callback_tx: Option<std::sync::mpsc::Sender<CallbackJob>>,
+    device_status: Arc<Mutex<HashMap<String, DeviceStatus>>>,
+    paused: Arc<AtomicBool>,
+    shutdown: watch::Receiver<bool>,
+    cancel: watch::Receiver<bool>,
+    debounce_time: Duration,
+    debounce_overrides: Arc<DebounceOverrides>,
+    axis_smoothing: Arc<AxisSmoothing>,
+    smoothing_targets: Arc<Mutex<SmoothingTargets>>,
+    axis_buttons: Arc<AxisButtons>,
+    max_open_retries: u32,
+    open_retry_delay: Duration,
+    activity_tx: broadcast::Sender<String>,
+    event_log: Arc<Mutex<VecDeque<InputEvent>>>,
+    live_monitors: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+/// Counts a monitor task as live for as long as it exists.
+///
+/// The count is taken back on drop, so a monitor that returns, panics or is aborted
+/// before its first poll all leave `DevicePool::device_health` accurate.
+struct LiveMonitor {
+    live_monitors: Arc<Mutex<HashMap<String, usize>>>,
+    device_name: String,
+}
+
+impl LiveMonitor {
+    fn new(live_monitors: &Arc<Mutex<HashMap<String, usize>>>, device_name: &str) -> Self {
+        *live_monitors
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(device_name.to_string())
+            .or_insert(0) += 1;
+        LiveMonitor {
+            live_monitors: Arc::clone(live_monitors),
+            device_name: device_name.to_string(),
+        }
+    }
+}
+
+impl Drop for LiveMonitor {
+    fn drop(&mut self) {
+        let mut live_monitors = self.live_monitors.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(count) = live_monitors.get_mut(&self.device_name) {
+            *count = count.saturating_sub(1);
+        }
+    }
+}
+
+impl MonitorContext {
+    /// Releases the shared registers from a poisoned state left behind by a panic.
+    fn clear_poison(&self) {
+        self.input_register.clear_poison();
+        self.last_button_time.clear_poison();
+        self.button_press_time.clear_poison();
+        self.button_callbacks.clear_poison();
+        self.device_status.clear_poison();
+        self.event_log.clear_poison();
+        self.smoothing_targets.clear_poison();
+    }
+
+    /// Returns true while a smoothed axis of `device_name` has not reached its reading.
+    fn smoothing_pending(&self, device_name: &str) -> bool {
+        self.smoothing_targets
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|(name, _)| name == device_name)
+    }
+
+    /// Appends `events` to the event log, dropping the oldest events beyond its capacity.
+    fn log_events(&self, events: Vec<InputEvent>) {
+        if events.is_empty() {
+            return;
+        }
+        let mut event_log = self.event_log.lock().unwrap();
+        event_log.extend(events);
+        let excess = event_log.len().saturating_sub(EVENT_LOG_CAPACITY);
+        event_log.drain(..excess);
+    }
+
+    /// Returns the debounce time of `code` on `device_name`.
+    fn debounce_for(&self, device_name: &str, code: u16) -> Duration {
+        debounce_time_for(
+            &self.debounce_overrides,
+            self.debounce_time,
+            device_name,
+            code,
+        )
+    }
+
+    /// Applies `update` to the status entry of `device_name`, creating it if needed.
+    fn update_status(&self, device_name: &str, update: impl FnOnce(&mut DeviceStatus)) {
+        let mut device_status = self.device_status.lock().unwrap();
+        update(device_status.entry(device_name.to_string()).or_default());
+    }
+
+    /// Zeroes the register entry of a device that can no longer be read.
+    ///
+    /// Without this, a device unplugged while a button was held would keep reporting
+    /// that button as pressed. Waiting fetches are woken up to see the zeroed entry.
+    fn release_device(&self, description: &DeviceDescription) {
+        let device_name = &description.device_name;
+        if let Some(input_data) = self.input_register.lock().unwrap().get_mut(device_name) {
+            *input_data = description.build_state();
+        }
+        self.button_press_time
+            .lock()
+            .unwrap()
+            .retain(|(name, _), _| name != device_name);
+        self.smoothing_targets
+            .lock()
+            .unwrap()
+            .retain(|(name, _), _| name != device_name);
+        self.register_version.fetch_add(1, Ordering::SeqCst);
+        self.register_changed.notify_waiters();
+        // Sending only fails without subscribers
+        let _ = self.activity_tx.send(device_name.clone());
+    }
+}
+
+/// A source of joystick states read by a monitor task.
+///
+/// Implemented by [`JoystickEventStream`], kept as a trait so the monitor loop can be
+/// driven without an input device.
+trait StateSource {
+    async fn next_state(&mut self) -> std::io::Result<JoystickState>;
+}
+
+impl StateSource for JoystickEventStream {
+    async fn next_state(&mut self) -> std::io::Result<JoystickState> {
+        JoystickEventStream::next_state(self).await
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+/// A status snapshot of a single described device.
+///
+/// # Fields
+///
+/// * `device_name` - The name of the described device
+/// * `connected` - Whether a device with this name is currently connected
+/// * `monitored` - Whether a monitor task is currently reading the device
+/// * `receiving_data` - Whether any input has arrived since monitoring started
+/// * `last_error` - The most recent open or read error reported by the monitor, if any
+pub struct DeviceReport {
+    pub device_name: String,
+    pub connected: bool,
+    pub monitored: bool,
+    pub receiving_data: bool,
+    pub last_error: Option<String>,
+}
+
+/// The kind of input an [`InputEvent`] was reported by.
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Axis,
+    Button,
+    Hat,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+/// A single input change, tagged with the device that reported it.
+///
+/// # Fields
+///
+/// * `device` - The name of the described device the input belongs to
+/// * `kind` - Whether the input is an axis, a button or a hat
+/// * `code` - The evdev code of the input, the logical code for rotated axes
+/// * `value` - The new value: the processed axis value, 1.0/0.0 for a button press or
+///   release, and -1.0, 0.0 or 1.0 for a hat
+pub struct InputEvent {
+    pub device: String,
+    pub kind: InputKind,
+    pub code: u16,
+    pub value: f32,
+}
+
+impl InputEvent {
+    fn new(device: &str, kind: InputKind, code: u16, value: f32) -> Self {
+        InputEvent {
+            device: device.to_string(),
+            kind,
+            code,
+            value,
+        }
+    }
 }
 
 /// Implementation of the DevicePool with methods for managing devices and input states.
@@ -45,24 +482,222 @@ impl DevicePool {
     /// - Configures running state and shutdown channel as None (not started)
     ///
     /// # Arguments
+    /// * `device_desc_files` - Paths to the TOML device description files
     /// * `debounce_seconds` - The debounce time in seconds as a floating-point value
     ///
     /// # Returns
     /// A new `DevicePool` instance ready for device management and input processing
+    ///
+    /// Description files that cannot be read or parsed are skipped, see
+    /// [`DevicePool::load_errors`]. Hotplug detection, open retries and the change
+    /// epsilon start disabled like in [`DevicePool::from_descriptions`] and are enabled
+    /// with [`DevicePool::set_hotplug`], [`DevicePool::set_open_retries`] and
+    /// [`DevicePool::set_change_epsilon`].
     pub fn new(device_desc_files: Vec<String>, debounce_seconds: f64) -> Self {
+        let (descriptions, load_errors) = Self::try_load_descriptions(&device_desc_files);
+        let mut pool = Self::from_descriptions(descriptions, debounce_seconds, false);
+        pool.load_errors = load_errors;
+        pool
+    }
+
+    /// Creates a new device pool from already parsed device descriptions.
+    ///
+    /// This behaves like [`DevicePool::new`] but skips the filesystem entirely, which
+    /// is useful when descriptions come from a database, the network or are built in code.
+    /// Failed device opens are not retried until [`DevicePool::set_open_retries`] is called,
+    /// and every axis change counts until [`DevicePool::set_change_epsilon`] is called.
+    ///
+    /// # Arguments
+    /// * `descriptions` - The device descriptions to monitor
+    /// * `debounce_seconds` - The debounce time in seconds as a floating-point value
+    /// * `hotplug` - Whether to watch for devices being plugged in or removed
+    ///
+    /// # Returns
+    /// A new `DevicePool` instance ready for device management and input processing
+    pub fn from_descriptions(
+        descriptions: Vec<DeviceDescription>,
+        debounce_seconds: f64,
+        hotplug: bool,
+    ) -> Self {
         let mut pool = Self {
             debounce_time: Duration::from_secs_f64(debounce_seconds),
+            debounce_overrides: Arc::new(HashMap::new()),
+            axis_smoothing: Arc::new(HashMap::new()),
+            smoothing_targets: Arc::new(Mutex::new(HashMap::new())),
+            axis_buttons: Arc::new(HashMap::new()),
             devices: Vec::new(),
             input_register: Arc::new(Mutex::new(HashMap::new())),
+            register_version: Arc::new(AtomicU64::new(0)),
+            register_changed: Arc::new(Notify::new()),
             last_input_register: Arc::new(Mutex::new(HashMap::new())),
             last_button_time: Arc::new(Mutex::new(HashMap::new())),
+            button_press_time: Arc::new(Mutex::new(HashMap::new())),
+            button_callbacks: Arc::new(Mutex::new(HashMap::new())),
+            device_status: Arc::new(Mutex::new(HashMap::new())),
             running: Arc::new(Mutex::new(false)),
+            paused: Arc::new(AtomicBool::new(false)),
             shutdown_tx: None,
+            cancel_tx: None,
+            supervisor: None,
+            hotplug,
+            hotplug_tx: None,
+            load_errors: Vec::new(),
+            runtime: None,
+            max_open_retries: 0,
+            open_retry_delay: DEFAULT_OPEN_RETRY_DELAY,
+            change_epsilon: 0.0,
+            activity_tx: broadcast::channel(ACTIVITY_CAPACITY).0,
+            event_log: Arc::new(Mutex::new(VecDeque::new())),
+            live_monitors: Arc::new(Mutex::new(HashMap::new())),
         };
-        pool.build_state(device_desc_files);
+        pool.build_state(descriptions);
         pool
     }
 
+    /// Watches for devices being plugged in or removed while monitoring, so reconnected
+    /// devices resume without calling `reset()`. Takes effect the next time monitoring
+    /// starts.
+    ///
+    /// # Arguments
+    /// * `hotplug` - Whether to run the hotplug watcher
+    pub fn set_hotplug(&mut self, hotplug: bool) {
+        self.hotplug = hotplug;
+    }
+
+    /// Retries opening a device whose open fails, e.g. while it is still busy or its
+    /// permissions are being set up right after it was plugged in.
+    ///
+    /// The `n`-th retry waits `base_delay * 2^(n - 1)`. Once every retry failed, the
+    /// monitor logs the error and leaves the device out until monitoring restarts (or
+    /// the hotplug watcher sees it again). Takes effect the next time monitoring starts.
+    ///
+    /// # Arguments
+    /// * `max_open_retries` - How often a failed open is retried, 0 to give up at once
+    /// * `base_delay` - The delay before the first retry
+    pub fn set_open_retries(&mut self, max_open_retries: u32, base_delay: Duration) {
+        self.max_open_retries = max_open_retries;
+        self.open_retry_delay = base_delay;
+    }
+
+    /// Sets how far an axis has to move before `fetch` reports it as changed.
+    ///
+    /// Axis values differing by less than `change_epsilon` from the last fetched state
+    /// count as unchanged, so sensor jitter does not wake waiting fetches; buttons and
+    /// hats are still compared exactly, see [`JoystickState::approx_eq`]. Movements are
+    /// measured against the last fetched state, so a slow drift is reported once it adds
+    /// up to the epsilon. 0.0, the default, reports every change.
+    pub fn set_change_epsilon(&mut self, change_epsilon: f32) {
+        self.change_epsilon = change_epsilon;
+    }
+
+    /// Runs the monitor tasks of the pool on `runtime`.
+    ///
+    /// By default the tasks are spawned onto the tokio runtime that calls `reset()` or
+    /// `start()`. With a runtime handle set, those calls no longer need to run inside a
+    /// tokio runtime, which lets applications with their own runtime (or none) embed
+    /// the pool without nesting runtimes. Takes effect the next time monitoring starts.
+    ///
+    /// # Arguments
+    /// * `runtime` - The handle of the runtime to run the monitors on
+    /// # Example
+    /// ```rust
+    /// let runtime = tokio::runtime::Runtime::new()?;
+    /// let mut pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// pool.set_runtime(runtime.handle().clone());
+    /// futures::executor::block_on(pool.reset());
+    /// ```
+    pub fn set_runtime(&mut self, runtime: Handle) {
+        self.runtime = Some(runtime);
+    }
+
+    /// Returns a channel receiving the hotplug events of the pool.
+    ///
+    /// Events are only sent while hotplug detection is enabled, see
+    /// [`DevicePool::set_hotplug`]. Calling this again replaces the previous channel, the
+    /// change takes effect the next time monitoring is started.
+    ///
+    /// # Returns
+    /// The receiving end of the hotplug event channel.
+    pub fn hotplug_events(&mut self) -> mpsc::UnboundedReceiver<HotplugEvent> {
+        let (hotplug_tx, hotplug_rx) = mpsc::unbounded_channel();
+        self.hotplug_tx = Some(hotplug_tx);
+        hotplug_rx
+    }
+
+    /// Returns a channel receiving the name of a device every time its state changes.
+    ///
+    /// A name is sent whenever a monitor records input from the device, and when the
+    /// state of a failed device is zeroed. Unlike `fetch`, nothing is consumed, so any
+    /// number of subscribers can watch for activity next to the regular fetches. A
+    /// subscriber that falls more than 256 notifications behind gets
+    /// `RecvError::Lagged` and continues with the newest ones.
+    ///
+    /// # Returns
+    /// The receiving end of the activity channel, seeing the changes from now on.
+    /// # Example
+    /// ```rust
+    /// let mut activity = pool.subscribe();
+    /// while let Ok(device_name) = activity.recv().await {
+    ///     println!("{} is active", device_name);
+    /// }
+    /// ```
+    pub fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.activity_tx.subscribe()
+    }
+
+    /// Loads device descriptions from TOML or JSON files, skipping any that fail to parse.
+    ///
+    /// # Arguments
+    /// * `device_desc_files` - Paths to the TOML or JSON device description files
+    ///
+    /// # Returns
+    /// The successfully parsed device descriptions, in file order.
+    pub fn load_descriptions(device_desc_files: &[String]) -> Vec<DeviceDescription> {
+        Self::try_load_descriptions(device_desc_files).0
+    }
+
+    /// Loads device descriptions like [`DevicePool::load_descriptions`], also returning
+    /// why the skipped files failed.
+    ///
+    /// Every failure is logged as a warning.
+    ///
+    /// # Arguments
+    /// * `device_desc_files` - Paths to the TOML or JSON device description files
+    ///
+    /// # Returns
+    /// The parsed descriptions in file order, and the `(path, error)` of every file that
+    /// could not be read or parsed.
+    pub fn try_load_descriptions(
+        device_desc_files: &[String],
+    ) -> (Vec<DeviceDescription>, Vec<(String, String)>) {
+        let mut descriptions = Vec::new();
+        let mut errors = Vec::new();
+        for desc_file in device_desc_files {
+            match DeviceDescription::from_file_rust(desc_file) {
+                Ok(description) => descriptions.push(description),
+                Err(e) => {
+                    warn!("Failed to load device description {}: {}", desc_file, e);
+                    errors.push((desc_file.clone(), e.to_string()));
+                }
+            }
+        }
+        (descriptions, errors)
+    }
+
+    /// Returns the description files that failed to load when the pool was created.
+    ///
+    /// # Returns
+    /// The path and error message of every skipped file, in file order.
+    pub fn load_errors(&self) -> Vec<(String, String)> {
+        self.load_errors.clone()
+    }
+
+    /// Records description files that failed to load before the pool was created.
+    #[cfg(feature = "python")]
+    pub(crate) fn set_load_errors(&mut self, load_errors: Vec<(String, String)>) {
+        self.load_errors = load_errors;
+    }
+
     /// Resets the device pool by stopping any ongoing monitoring,
     /// clearing the input register, and restarting monitoring.
     ///
@@ -72,12 +707,36 @@ impl DevicePool {
     /// # Returns
     /// A vector of device names that are currently connected and monitored.
     pub async fn reset(&mut self) -> Vec<String> {
-        self.stop_monitoring().await;
+        self.reset_with(false).await
+    }
+
+    /// Resets the device pool like [`DevicePool::reset`], but keeps the current axis values.
+    ///
+    /// Buttons and hats go back to their defaults and the debounce and hold bookkeeping
+    /// is cleared, while `axes` and `raw_axes` keep the positions they had before the
+    /// reset, so UIs do not see the sticks jump to the centre.
+    ///
+    /// # Returns
+    /// A vector of device names that are currently connected and monitored.
+    pub async fn reset_preserve_axes(&mut self) -> Vec<String> {
+        self.reset_with(true).await
+    }
+
+    /// Shared implementation of `reset` and `reset_preserve_axes`.
+    async fn reset_with(&mut self, preserve_axes: bool) -> Vec<String> {
+        self.stop_monitoring(None).await;
+        let snapshot = preserve_axes.then(|| self.input_register.lock().unwrap().clone());
         self.reset_input_register();
+        if let Some(snapshot) = snapshot {
+            self.restore_axes(snapshot);
+        }
         {
             let mut last_button_time = self.last_button_time.lock().unwrap();
             last_button_time.clear();
+            let mut button_press_time = self.button_press_time.lock().unwrap();
+            button_press_time.clear();
         }
+        self.event_log.lock().unwrap().clear();
         self.start_monitoring().await;
         self.check_devices()
     }
@@ -97,13 +756,13 @@ impl DevicePool {
     /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
     /// let current_state = pool.fetch_nowait()?;
     /// ```
-    pub fn fetch_nowait(&self) -> Result<HashMap<String, JoystickState>, String> {
+    pub fn fetch_nowait(&self) -> Result<HashMap<String, JoystickState>, DevicePoolError> {
         let running = *self.running.lock().unwrap();
         if !running {
-            return Err("Device monitoring is not running. Call reset() first.".to_string());
+            return Err(DevicePoolError::NotRunning);
         }
 
-        let current_input = {
+        let mut current_input = {
             let input_register = self.input_register.lock().unwrap();
             input_register.clone()
         };
@@ -113,395 +772,3798 @@ impl DevicePool {
             *last_input_register = current_input.clone();
         }
 
-        self.reset_trigger_register();
+        self.reset_trigger_register(None);
+        self.refresh_hold_times(&mut current_input);
         Ok(current_input)
     }
 
-    /// Fetches the current input state, waiting for changes or a timeout.
-    ///
-    /// This method continuously checks the input state until a change is detected
-    /// or the specified timeout duration is reached.
-    /// If a change is detected, it updates the last input register and resets the trigger register.
+    /// Fetches the current input state without waiting, but only if it changed.
     ///
-    /// # Arguments
-    /// * `timeout_duration` - An optional duration to wait for changes before timing out.
+    /// Unlike [`DevicePool::fetch_nowait`], the last input register and the triggers are
+    /// left untouched when nothing changed since the previous fetch, so a caller can
+    /// cheaply skip work on unchanged input. Axis changes within the change epsilon do
+    /// not count, like for [`DevicePool::fetch`].
     ///
     /// # Returns
-    /// A `Result` containing a `HashMap` of the current input states if successful,
-    /// or an error message if the operation times out or fails.
+    /// `Some` with the current input states if they differ from the last fetched ones,
+    /// `None` otherwise.
     /// # Errors
-    /// Returns an error if the device monitoring is not running or if the operation times out.
+    /// Returns an error if the device monitoring is not running.
     /// # Example
     /// ```rust
     /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
-    /// let current_state = pool.fetch(Some(Duration::from_secs(5))).await?;
+    /// if let Some(states) = pool.fetch_nowait_changed()? {
+    ///     println!("{:?}", states);
+    /// }
     /// ```
-    pub async fn fetch(
+    pub fn fetch_nowait_changed(
         &self,
-        timeout_duration: Option<Duration>,
-    ) -> Result<HashMap<String, JoystickState>, String> {
-        let start_time = Instant::now();
+    ) -> Result<Option<HashMap<String, JoystickState>>, DevicePoolError> {
+        let running = *self.running.lock().unwrap();
+        if !running {
+            return Err(DevicePoolError::NotRunning);
+        }
 
-        loop {
-            let running = *self.running.lock().unwrap();
-            if !running {
-                let input_register = self.input_register.lock().unwrap();
-                return Ok(input_register.clone());
+        let mut current_input = {
+            let input_register = self.input_register.lock().unwrap();
+            let mut last_input_register = self.last_input_register.lock().unwrap();
+            if states_approx_eq(&input_register, &last_input_register, self.change_epsilon) {
+                return Ok(None);
             }
+            *last_input_register = input_register.clone();
+            input_register.clone()
+        };
 
-            let current_input = {
-                let input_register = self.input_register.lock().unwrap();
-                input_register.clone()
-            };
+        self.reset_trigger_register(None);
+        self.refresh_hold_times(&mut current_input);
+        Ok(Some(current_input))
+    }
 
-            let last_input = {
-                let last_input_register = self.last_input_register.lock().unwrap();
-                last_input_register.clone()
-            };
+    /// Registers a callback fired whenever `code` on `device_name` goes from released
+    /// to pressed.
+    ///
+    /// Callbacks run on a single worker thread of the pool rather than on the monitor
+    /// task, so a slow callback delays other callbacks but never the input register.
+    /// Presses are handed to the worker in the order they were read, and several
+    /// callbacks registered for the same button are called in registration order.
+    /// Presses rejected by the debounce filter do not fire.
+    ///
+    /// # Arguments
+    /// * `device_name` - The name of the device the button belongs to.
+    /// * `code` - The button code to watch.
+    /// * `callback` - The function to call on every press.
+    pub fn on_button(&self, device_name: &str, code: u16, callback: ButtonCallback) {
+        let mut callbacks = self.button_callbacks.lock().unwrap();
+        callbacks
+            .entry((device_name.to_string(), code))
+            .or_default()
+            .push(callback);
+    }
 
-            if current_input != last_input {
-                {
-                    let mut last_input_register = self.last_input_register.lock().unwrap();
-                    *last_input_register = current_input.clone();
-                }
-                self.reset_trigger_register();
-                return Ok(current_input);
-            }
+    /// Returns true while the pool is monitoring its devices.
+    pub fn is_running(&self) -> bool {
+        *self.running.lock().unwrap()
+    }
 
-            if let Some(timeout_dur) = timeout_duration {
-                if start_time.elapsed() > timeout_dur {
-                    return Err("Fetch operation timed out".to_string());
-                }
-            }
+    /// Pauses input updates without stopping monitoring.
+    ///
+    /// The monitor tasks stay alive and keep reading their devices, but discard what
+    /// they read, so the input register holds the state it had when the pool was paused.
+    /// Unlike [`DevicePool::stop`], resuming needs neither re-enumeration nor new tasks.
+    /// The flag is independent of `stop()` and `reset()`.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Resumes input updates after [`DevicePool::pause`].
+    ///
+    /// Inputs reported while paused are not replayed, the register catches up with
+    /// each input on its next event. Only the undebounced button levels (`held` and
+    /// the hold times) keep following the devices while paused, so releases are not
+    /// missed.
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+    }
 
-            sleep(Duration::from_millis(10)).await;
+    /// Returns true while input updates are paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Returns a handle to the pool flags, for wrappers that must read or toggle them
+    /// without locking the whole pool.
+    #[cfg(feature = "python")]
+    pub(crate) fn status(&self) -> PoolStatus {
+        PoolStatus {
+            running: Arc::clone(&self.running),
+            paused: Arc::clone(&self.paused),
+            input_register: Arc::clone(&self.input_register),
+            live_monitors: Arc::clone(&self.live_monitors),
+            device_names: self
+                .devices
+                .iter()
+                .map(|desc| desc.device_name.clone())
+                .collect(),
         }
     }
 
-    /// Builds the device pool state from the provided device description files.
+    /// Returns the shared running flag, for wrappers that must check it without
+    /// locking the whole pool.
+    #[cfg(feature = "python")]
+    pub(crate) fn running_flag(&self) -> Arc<Mutex<bool>> {
+        Arc::clone(&self.running)
+    }
+
+    /// Returns the names of the devices in the input register, in description order.
     ///
-    /// This method reads the device descriptions from the specified files,
-    /// initializes the input register with the device states, and populates
-    /// the devices vector with the parsed device descriptions.
+    /// These are the devices a running pool reports states for, whether or not they
+    /// are connected right now.
+    pub fn monitored_devices(&self) -> Vec<String> {
+        let input_register = self.input_register.lock().unwrap();
+        self.devices
+            .iter()
+            .map(|desc| desc.device_name.clone())
+            .filter(|device_name| input_register.contains_key(device_name))
+            .collect()
+    }
+
+    /// Returns whether the monitor task of each device is still running.
     ///
-    /// # Arguments
-    /// * `device_desc_files` - A vector of strings representing the paths to the device description files.
+    /// A device is `false` once every monitor task spawned for it has exited, whether
+    /// it returned, panicked or was stopped. Devices that never had a monitor task
+    /// are absent.
+    pub fn device_health(&self) -> HashMap<String, bool> {
+        device_health(&self.live_monitors)
+    }
+
+    /// Returns the last known input state without any checks or side effects.
+    ///
+    /// Unlike `fetch_nowait`, this works whether or not monitoring is running and
+    /// leaves the trigger and last input registers untouched, which makes it suitable
+    /// for inspecting the final state after `stop()`.
     ///
+    /// # Returns
+    /// A `HashMap` containing the current input states for all devices.
     /// # Example
     /// ```rust
-    /// let device_desc_files = vec!["device1.toml".to_string(), "device2.toml".to_string()];
-    /// let mut pool = DevicePool::new(device_desc_files, 0.1);
-    /// pool.build_state(device_desc_files);
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// let snapshot = pool.fetch_last();
     /// ```
-    fn build_state(&mut self, device_desc_files: Vec<String>) {
-        self.devices.clear();
-        let mut input_register = self.input_register.lock().unwrap();
-        input_register.clear();
+    pub fn fetch_last(&self) -> HashMap<String, JoystickState> {
+        let mut snapshot = self.input_register.lock().unwrap().clone();
+        self.refresh_hold_times(&mut snapshot);
+        snapshot
+    }
 
-        for desc_file in device_desc_files {
-            if let Ok(desc) = DeviceDescription::from_toml(&desc_file) {
-                let device_name = desc.device_name.clone();
-                let state = desc.build_state();
-                input_register.insert(device_name, state);
-                self.devices.push(desc);
+    /// Updates the button hold durations of `snapshot` to the time of the call.
+    ///
+    /// The register only holds the duration measured at the last event, so held
+    /// buttons are recomputed from their press time whenever a snapshot is handed out.
+    fn refresh_hold_times(&self, snapshot: &mut HashMap<String, JoystickState>) {
+        let press_times = self.button_press_time.lock().unwrap();
+        let now = Instant::now();
+
+        for state in snapshot.values_mut() {
+            for &code in state.buttons.keys() {
+                state.button_hold_ms.insert(code, 0);
+            }
+        }
+        for ((device_name, code), &pressed_at) in press_times.iter() {
+            if let Some(state) = snapshot.get_mut(device_name) {
+                let held = now.duration_since(pressed_at).as_millis() as u64;
+                state.button_hold_ms.insert(*code, held);
             }
         }
     }
 
-    /// Resets the input register to the initial state based on the device descriptions.
+    /// Fetches the current input state, waiting for changes or a timeout.
     ///
-    /// This method initializes the input register with the default states of all devices
-    /// defined in the device descriptions. It also updates the last input register
-    /// to match the current input register state.
+    /// This method continuously checks the input state until a change is detected
+    /// or the specified timeout duration is reached. Instead of polling, it sleeps until a
+    /// monitor task signals a register write, so changes are returned as soon as they
+    /// arrive. The registers are only cloned and compared when the register version
+    /// shows that something was written since the previous check.
+    /// If a change is detected, it updates the last input register and resets the trigger register.
+    ///
+    /// With a `device_filter`, only the listed devices are compared, returned and have
+    /// their triggers reset. Changes to other devices do not end the wait and stay
+    /// pending for the next unfiltered fetch.
+    ///
+    /// # Arguments
+    /// * `timeout_duration` - An optional duration to wait for changes before timing out.
+    /// * `device_filter` - An optional list of device names to restrict the fetch to.
     ///
+    /// # Returns
+    /// A `Result` containing a `HashMap` of the current input states if successful,
+    /// or an error message if the operation times out or fails.
+    /// # Errors
+    /// Returns an error if the device monitoring is not running or if the operation times out.
     /// # Example
     /// ```rust
-    /// let mut pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
-    /// pool.reset_input_register();
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// let current_state = pool.fetch(Some(Duration::from_secs(5)), None).await?;
     /// ```
-    fn reset_input_register(&self) {
-        let mut input_register = self.input_register.lock().unwrap();
-        let mut last_input_register = self.last_input_register.lock().unwrap();
-
-        for desc in &self.devices {
-            let state = desc.build_state();
-            input_register.insert(desc.device_name.clone(), state.clone());
-        }
-        *last_input_register = input_register.clone();
+    pub async fn fetch(
+        &self,
+        timeout_duration: Option<Duration>,
+        device_filter: Option<Vec<String>>,
+    ) -> Result<HashMap<String, JoystickState>, DevicePoolError> {
+        self.fetch_with(timeout_duration, device_filter, false)
+            .await
     }
 
-    /// Resets the trigger register by clearing all button and hat states.
+    /// Fetches the input state of a single device, waiting for it to change or a timeout.
     ///
-    /// This method iterates through the input register and sets all button and hat values to zero,
-    /// effectively resetting the trigger states for all devices.
+    /// Behaves like [`DevicePool::fetch`] filtered to `device_name`, but only that
+    /// device's state is copied out of the register and handed back.
+    ///
+    /// # Arguments
+    /// * `device_name` - The name of the device to fetch.
+    /// * `timeout_duration` - An optional duration to wait for changes before timing out.
     ///
+    /// # Errors
+    /// Returns [`DevicePoolError::UnknownDevice`] if no description of `device_name` is
+    /// loaded, and the errors of `fetch` otherwise. A stopped pool that never saw the
+    /// device returns [`DevicePoolError::NotRunning`].
     /// # Example
     /// ```rust
     /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
-    /// pool.reset_trigger_register();
+    /// let stick = pool.fetch_device("Stick", Some(Duration::from_secs(5))).await?;
     /// ```
-    fn reset_trigger_register(&self) {
-        let mut input_register = self.input_register.lock().unwrap();
-        for (_device_name, input_data) in input_register.iter_mut() {
-            for (_button_key, button_value) in input_data.buttons.iter_mut() {
-                *button_value = 0;
-            }
-            for (_hat_key, hat_value) in input_data.hats.iter_mut() {
-                *hat_value = 0;
-            }
+    pub async fn fetch_device(
+        &self,
+        device_name: &str,
+        timeout_duration: Option<Duration>,
+    ) -> Result<JoystickState, DevicePoolError> {
+        if !self
+            .devices
+            .iter()
+            .any(|desc| desc.device_name == device_name)
+        {
+            return Err(DevicePoolError::UnknownDevice(device_name.to_string()));
         }
+
+        let mut states = self
+            .fetch_with(timeout_duration, Some(vec![device_name.to_string()]), false)
+            .await?;
+        states
+            .remove(device_name)
+            .ok_or(DevicePoolError::NotRunning)
     }
 
-    /// Checks the currently connected devices against the input register.
+    /// Returns the input events reported by all devices since the previous call, waiting
+    /// for at least one or a timeout.
     ///
-    /// This method fetches the list of connected joysticks and compares them
-    /// with the input register. It returns a vector of device names that are
-    /// currently registered in the input register.
+    /// Every axis, button and hat change written to the input register is also logged as
+    /// an [`InputEvent`] carrying the device name, so merged streams keep track of where
+    /// each input came from. The log is independent of `fetch`: fetches neither consume
+    /// events nor are they affected by this method. It keeps the latest
+    /// `EVENT_LOG_CAPACITY` events and is cleared by `reset()`.
+    ///
+    /// # Arguments
+    /// * `timeout_duration` - An optional duration to wait for events. `None` waits forever.
     ///
     /// # Returns
-    /// A vector of strings containing the names of devices that are currently connected
-    /// and registered in the input register.
+    /// The logged events in the order they were reported, empty if the timeout expired.
+    /// A stopped pool returns the events logged before it stopped without waiting.
     /// # Example
     /// ```rust
     /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
-    /// let connected_devices = pool.check_devices();
+    /// for event in pool.events(Some(Duration::from_millis(100))).await {
+    ///     println!("{} {:?} {} = {}", event.device, event.kind, event.code, event.value);
+    /// }
     /// ```
-    fn check_devices(&self) -> Vec<String> {
-        let devices = fetch_connected_joysticks();
-        let input_register = self.input_register.lock().unwrap();
+    pub async fn events(&self, timeout_duration: Option<Duration>) -> Vec<InputEvent> {
+        let deadline = timeout_duration.map(|dur| tokio::time::Instant::now() + dur);
 
-        devices
-            .into_iter()
-            .filter_map(|device_info| {
-                if input_register.contains_key(&device_info.name) {
-                    Some(device_info.name)
-                } else {
-                    None
+        loop {
+            // Register interest before checking, so an event in between is not missed
+            let changed = self.register_changed.notified();
+            tokio::pin!(changed);
+            changed.as_mut().enable();
+
+            let events: Vec<InputEvent> = self.event_log.lock().unwrap().drain(..).collect();
+            if !events.is_empty() || !self.is_running() {
+                return events;
+            }
+
+            match deadline {
+                Some(deadline) => {
+                    if tokio::time::timeout_at(deadline, changed).await.is_err() {
+                        return Vec::new();
+                    }
                 }
-            })
-            .collect()
+                None => changed.await,
+            }
+        }
     }
 
-    /// Starts monitoring the connected devices for input changes.
+    /// Fetches the input state like [`DevicePool::fetch`], blocking the calling thread.
     ///
-    /// This method initializes the monitoring tasks for each connected joystick,
-    /// allowing them to report input states asynchronously. It sets up a shutdown channel
-    /// to gracefully stop monitoring when needed.
+    /// Meant for callers outside of an async context. The fetch runs on the runtime set
+    /// with [`DevicePool::set_runtime`], or on a temporary single-threaded runtime
+    /// otherwise. Either way it must not be called from within an async task, blocking
+    /// on a runtime there panics. The monitor tasks still need a running runtime of
+    /// their own to deliver changes, see `set_runtime`.
+    ///
+    /// # Arguments
+    /// * `timeout_duration` - An optional duration to wait for changes before timing out.
     ///
+    /// # Errors
+    /// Returns the errors of `fetch`, or an I/O error if no runtime could be created.
     /// # Example
     /// ```rust
-    /// let mut pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
-    /// pool.start_monitoring().await;
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// let current_state = pool.fetch_blocking(Some(Duration::from_millis(100)))?;
     /// ```
-    async fn start_monitoring(&mut self) {
-        let running = *self.running.lock().unwrap();
-        if running {
-            return;
+    pub fn fetch_blocking(
+        &self,
+        timeout_duration: Option<Duration>,
+    ) -> Result<HashMap<String, JoystickState>, DevicePoolError> {
+        let fetch = self.fetch(timeout_duration, None);
+        match &self.runtime {
+            Some(runtime) => runtime.block_on(fetch),
+            None => tokio::runtime::Builder::new_current_thread()
+                .enable_time()
+                .build()?
+                .block_on(fetch),
         }
+    }
+
+    /// Fetches the input state like [`DevicePool::fetch`], but returns the current state
+    /// instead of an error when the timeout expires.
+    ///
+    /// On timeout the current state is handed out as if it had changed: the last input
+    /// register is updated and the triggers are reset.
+    ///
+    /// # Arguments
+    /// * `timeout_duration` - An optional duration to wait for changes.
+    /// * `device_filter` - An optional list of device names to restrict the fetch to.
+    ///
+    /// # Errors
+    /// Never returns [`DevicePoolError::Timeout`], the error type is shared with `fetch`.
+    /// # Example
+    /// ```rust
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// let current_state = pool.fetch_or_current(Some(Duration::from_millis(16)), None).await?;
+    /// ```
+    pub async fn fetch_or_current(
+        &self,
+        timeout_duration: Option<Duration>,
+        device_filter: Option<Vec<String>>,
+    ) -> Result<HashMap<String, JoystickState>, DevicePoolError> {
+        self.fetch_with(timeout_duration, device_filter, true).await
+    }
 
-        *self.running.lock().unwrap() = true;
+    /// Shared implementation of `fetch` and `fetch_or_current`.
+    async fn fetch_with(
+        &self,
+        timeout_duration: Option<Duration>,
+        device_filter: Option<Vec<String>>,
+        return_on_timeout: bool,
+    ) -> Result<HashMap<String, JoystickState>, DevicePoolError> {
+        let device_filter = device_filter.as_deref();
+        let deadline = timeout_duration.map(|dur| tokio::time::Instant::now() + dur);
+        let mut checked_version = None;
 
-        let (shutdown_tx, mut shutdown_rx) = mpsc::channel(1);
-        self.shutdown_tx = Some(shutdown_tx);
+        loop {
+            // Register interest before checking, so a write in between is not missed
+            let changed = self.register_changed.notified();
+            tokio::pin!(changed);
+            changed.as_mut().enable();
 
-        let input_register = Arc::clone(&self.input_register);
-        let last_button_time = Arc::clone(&self.last_button_time);
-        let running = Arc::clone(&self.running);
-        let debounce_time = self.debounce_time;
+            let running = *self.running.lock().unwrap();
+            if !running {
+                return Ok(select_devices(&self.fetch_last(), device_filter));
+            }
 
-        tokio::spawn(async move {
-            let devices = fetch_connected_joysticks();
-            let mut tasks = Vec::new();
-
-            for device_info in devices {
-                let input_register_clone = Arc::clone(&input_register);
-                let last_button_time_clone = Arc::clone(&last_button_time);
-                let running_clone = Arc::clone(&running);
-
-                let task = tokio::spawn(async move {
-                    Self::monitor_device(
-                        device_info.path,
-                        device_info.name,
-                        input_register_clone,
-                        last_button_time_clone,
-                        running_clone,
-                        debounce_time,
-                    )
-                    .await;
-                });
-                tasks.push(task);
+            let version = self.register_version.load(Ordering::SeqCst);
+            if checked_version != Some(version) {
+                checked_version = Some(version);
+
+                let current_input = {
+                    let input_register = self.input_register.lock().unwrap();
+                    select_devices(&input_register, device_filter)
+                };
+
+                let last_input = {
+                    let last_input_register = self.last_input_register.lock().unwrap();
+                    select_devices(&last_input_register, device_filter)
+                };
+
+                if !states_approx_eq(&current_input, &last_input, self.change_epsilon) {
+                    return Ok(self.take_current(current_input, device_filter));
+                }
             }
 
-            tokio::select! {
-                _ = shutdown_rx.recv() => {
-                    for task in tasks {
-                        task.abort();
+            match deadline {
+                Some(deadline) => {
+                    if tokio::time::timeout_at(deadline, changed).await.is_err() {
+                        if !return_on_timeout {
+                            return Err(DevicePoolError::Timeout);
+                        }
+                        let current_input = {
+                            let input_register = self.input_register.lock().unwrap();
+                            select_devices(&input_register, device_filter)
+                        };
+                        return Ok(self.take_current(current_input, device_filter));
                     }
                 }
+                None => changed.await,
             }
-        });
+        }
     }
 
-    /// Stops monitoring the devices and cleans up resources.
+    /// Hands out `current_input` as the result of a fetch.
     ///
-    /// This method sets the running state to false, signaling all monitoring tasks to stop.
-    /// It also sends a shutdown signal through the channel if it exists.
+    /// Stores it as the last input of its devices and resets their triggers.
+    fn take_current(
+        &self,
+        mut current_input: HashMap<String, JoystickState>,
+        device_filter: Option<&[String]>,
+    ) -> HashMap<String, JoystickState> {
+        {
+            let mut last_input_register = self.last_input_register.lock().unwrap();
+            for (device_name, state) in &current_input {
+                last_input_register.insert(device_name.clone(), state.clone());
+            }
+        }
+        self.reset_trigger_register(device_filter);
+        self.refresh_hold_times(&mut current_input);
+        current_input
+    }
+
+    /// Waits until every described device is connected, or the timeout expires.
+    ///
+    /// The connected devices are re-enumerated periodically until each loaded
+    /// device description has a matching connected device.
+    ///
+    /// # Arguments
+    /// * `timeout_duration` - An optional duration to wait before giving up. `None` waits forever.
     ///
+    /// # Returns
+    /// The names of the connected described devices once all of them are present.
+    /// # Errors
+    /// Returns [`DevicePoolError::MissingDevices`] with the devices that are still missing
+    /// when the timeout expires.
     /// # Example
     /// ```rust
-    /// let mut pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
-    /// pool.stop_monitoring().await;
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// let devices = pool.wait_for_all_devices(Some(Duration::from_secs(10))).await?;
     /// ```
-    async fn stop_monitoring(&mut self) {
-        let running = *self.running.lock().unwrap();
-        if !running {
-            return;
-        }
+    pub async fn wait_for_all_devices(
+        &self,
+        timeout_duration: Option<Duration>,
+    ) -> Result<Vec<String>, DevicePoolError> {
+        let start_time = Instant::now();
 
-        *self.running.lock().unwrap() = false;
+        loop {
+            let missing = self.missing_devices();
+            if missing.is_empty() {
+                return Ok(self.check_devices());
+            }
 
-        if let Some(shutdown_tx) = self.shutdown_tx.take() {
-            let _ = shutdown_tx.send(()).await;
+            if let Some(timeout_dur) = timeout_duration {
+                if start_time.elapsed() > timeout_dur {
+                    return Err(DevicePoolError::MissingDevices(missing));
+                }
+            }
+
+            sleep(Duration::from_millis(100)).await;
         }
     }
 
-    /// Monitors a single joystick device for input changes.
+    /// Returns the names of described devices that are not currently connected.
     ///
-    /// This method continuously reads the state of the joystick and updates the input register
-    /// with the current axes, buttons, and hats. It implements debouncing logic to prevent
-    /// rapid button press registrations.
+    /// A merged description counts as missing until all of its members are connected.
+    fn missing_devices(&self) -> Vec<String> {
+        let connected = self.check_devices();
+
+        self.devices
+            .iter()
+            .filter(|desc| !connected.contains(&desc.device_name))
+            .map(|desc| desc.device_name.clone())
+            .collect()
+    }
+
+    /// Builds a status report for every described device.
     ///
-    /// # Arguments
-    /// * `device_path` - The file path of the joystick device to monitor.
-    /// * `device_name` - The name of the joystick device.
-    /// * `input_register` - A shared reference to the input register where the state will be stored.
-    /// * `last_button_time` - A shared reference to track the last time each button was pressed.
-    /// * `running` - A shared reference indicating whether the monitoring is active.
-    /// * `debounce_time` - The duration to wait before allowing another button press registration.
+    /// The report combines the connection check with the bookkeeping of the
+    /// monitor tasks, so a dashboard can render everything from a single call.
     ///
+    /// # Returns
+    /// One `DeviceReport` per loaded device description, in description order.
     /// # Example
     /// ```rust
-    /// let device_path = "/dev/input/js0".to_string();
-    /// let device_name = "Joystick 1".to_string();
-    /// let input_register = Arc::new(Mutex::new(HashMap::new()));
-    /// let last_button_time = Arc::new(Mutex::new(HashMap::new()));
-    /// let running = Arc::new(Mutex::new(true));
-    /// let debounce_time = Duration::from_millis(100);
-    /// DevicePool::monitor_device(device_path, device_name, input_register, last_button_time, running, debounce_time).await;
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// for report in pool.device_report() {
+    ///     println!("{}: connected={}", report.device_name, report.connected);
+    /// }
     /// ```
-    async fn monitor_device(
-        device_path: String,
-        device_name: String,
-        input_register: Arc<Mutex<HashMap<String, JoystickState>>>,
-        last_button_time: Arc<Mutex<HashMap<u16, Instant>>>,
-        running: Arc<Mutex<bool>>,
-        debounce_time: Duration,
-    ) {
-        let mut joystick = match Joystick::new(&device_path) {
-            Ok(js) => js,
-            Err(e) => {
-                eprintln!("Failed to create joystick for {}: {}", device_name, e);
-                return;
-            }
-        };
+    pub fn device_report(&self) -> Vec<DeviceReport> {
+        let connected = self.check_devices();
+        let device_status = self.device_status.lock().unwrap();
 
-        println!("Started monitoring {}", device_name);
-
-        while *running.lock().unwrap() {
-            if let Ok(state) = joystick.get_state() {
-                let axes = state.axes;
-                let buttons = state.buttons;
-                let hats = state.hats;
-
-                let mut input_register = input_register.lock().unwrap();
-
-                if let Some(input_data) = input_register.get_mut(&device_name) {
-                    // Update axes
-                    for (code, value) in axes {
-                        input_data.axes.insert(code, value);
-                    }
+        self.devices
+            .iter()
+            .map(|desc| {
+                let status = device_status
+                    .get(&desc.device_name)
+                    .cloned()
+                    .unwrap_or_default();
+                DeviceReport {
+                    device_name: desc.device_name.clone(),
+                    connected: connected.contains(&desc.device_name),
+                    monitored: status.monitored,
+                    receiving_data: status.last_input.is_some(),
+                    last_error: status.last_error,
+                }
+            })
+            .collect()
+    }
 
-                    // Update buttons with debouncing
-                    // Update buttons with debouncing
-                    for (code, value) in buttons {
-                        if Self::should_update_input(code, &last_button_time, debounce_time) {
-                            input_data.buttons.insert(code, value);
-                        }
-                    }
+    /// Checks the loaded descriptions against the inputs their devices really have.
+    ///
+    /// Every connected device matching a description is opened and the described axis,
+    /// button and hat codes it does not report are collected, see
+    /// [`DeviceDescription::missing_codes`]. The inputs of a merged description are
+    /// looked up across all of its members. Descriptions whose devices are not connected
+    /// or cannot be opened are left out, since there is nothing to compare them with.
+    ///
+    /// # Returns
+    /// A `HashMap` from device name to its missing codes; an empty list means the
+    /// description matches the hardware.
+    /// # Example
+    /// ```rust
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// for (device_name, codes) in pool.validate_against_hardware() {
+    ///     if !codes.is_empty() {
+    ///         eprintln!("{} has no inputs {:?}", device_name, codes);
+    ///     }
+    /// }
+    /// ```
+    pub fn validate_against_hardware(&self) -> HashMap<String, Vec<u16>> {
+        Self::validate_with(&self.devices, fetch_connected_joysticks(true), |path| {
+            Joystick::new(path).map(|joystick| joystick.layout().capabilities())
+        })
+    }
 
-                    // Update hats with debouncing
-                    for (code, value) in hats {
-                        if Self::should_update_input(code, &last_button_time, debounce_time) {
-                            input_data.hats.insert(code, value);
-                        }
+    /// Compares `descriptions` with the capabilities `capabilities_of` reports for the
+    /// `connected` devices they match.
+    fn validate_with(
+        descriptions: &[DeviceDescription],
+        connected: Vec<JoystickInfo>,
+        mut capabilities_of: impl FnMut(&str) -> std::io::Result<HashMap<String, Vec<u16>>>,
+    ) -> HashMap<String, Vec<u16>> {
+        let mut found: HashMap<String, HashMap<String, Vec<u16>>> = HashMap::new();
+        for (device_info, description) in Self::described_devices(connected, descriptions) {
+            match capabilities_of(&device_info.path) {
+                Ok(capabilities) => {
+                    let merged = found.entry(description.device_name).or_default();
+                    for (kind, codes) in capabilities {
+                        merged.entry(kind).or_default().extend(codes);
                     }
                 }
+                Err(e) => warn!(
+                    "Failed to open {} to validate {}: {}",
+                    device_info.path, description.device_name, e
+                ),
             }
-
-            sleep(Duration::from_millis(10)).await;
         }
 
-        println!("Stopped monitoring {}", device_name);
+        descriptions
+            .iter()
+            .filter_map(|desc| {
+                let capabilities = found.get(&desc.device_name)?;
+                Some((desc.device_name.clone(), desc.missing_codes(capabilities)))
+            })
+            .collect()
     }
 
-    /// Determines if an input should be updated based on the debounce time.
-    ///
-    /// This method checks the last time a button was pressed and compares it
-    /// with the current time. If the time since the last press is less than the
-    /// debounce time, it returns false, indicating that the input should not be updated.
-    /// Otherwise, it updates the last pressed time and returns true.
+    /// Returns the last error recorded for each device, if any.
     ///
-    /// # Arguments
-    /// * `code` - The code of the button or hat being checked.
-    /// * `last_button_time` - A shared reference to the last button press times.
-    /// * `debounce_time` - The duration to wait before allowing another button press registration.
+    /// Errors come from failed device opens, failed reads and crashed monitor tasks.
     ///
     /// # Returns
-    /// A boolean indicating whether the input should be updated (true) or ignored (false).
-    fn should_update_input(
-        code: u16,
-        last_button_time: &Arc<Mutex<HashMap<u16, Instant>>>,
-        debounce_time: Duration,
-    ) -> bool {
-        let mut last_times = last_button_time.lock().unwrap();
-        let now = Instant::now();
+    /// A `HashMap` from device name to its most recent error message.
+    /// # Example
+    /// ```rust
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// for (device_name, error) in pool.last_errors() {
+    ///     eprintln!("{}: {}", device_name, error);
+    /// }
+    /// ```
+    pub fn last_errors(&self) -> HashMap<String, String> {
+        let device_status = self.device_status.lock().unwrap();
 
-        if let Some(&last_time) = last_times.get(&code) {
-            if now.duration_since(last_time) < debounce_time {
-                return false;
-            }
-        }
+        device_status
+            .iter()
+            .filter_map(|(device_name, status)| {
+                status
+                    .last_error
+                    .clone()
+                    .map(|error| (device_name.clone(), error))
+            })
+            .collect()
+    }
 
-        last_times.insert(code, now);
-        true
+    /// Returns the debounce time of an input of `device_name`.
+    ///
+    /// This is the per-input `debounce` of its description if set, otherwise the pool
+    /// wide debounce time.
+    pub fn debounce_for(&self, device_name: &str, code: u16) -> Duration {
+        debounce_time_for(
+            &self.debounce_overrides,
+            self.debounce_time,
+            device_name,
+            code,
+        )
     }
 
-    /// Starts monitoring the devices for input changes.
+    /// Returns the button and hat codes that are currently inside their debounce window.
     ///
-    /// This method checks if the device pool is already running. If not, it starts monitoring
-    /// the devices by calling `start_monitoring()`. It returns a vector of device names that are
-    /// currently connected and registered in the input register.
+    /// A code is considered debounced when its last accepted press happened less than
+    /// its debounce time ago, meaning further presses of it are being suppressed right now.
+    /// Debouncing is tracked per device, so each code is reported with its device.
     ///
     /// # Returns
-    /// A vector of strings containing the names of devices that are currently connected
-    /// and registered in the input register.
+    /// A vector of `(device_name, code)` pairs of the suppressed codes, sorted by device
+    /// name and code.
     /// # Example
     /// ```rust
-    /// let mut pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
-    /// let connected_devices = pool.start().await;
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// let suppressed = pool.debounced_codes();
     /// ```
-    pub async fn stop(&mut self) {
-        self.stop_monitoring().await;
+    pub fn debounced_codes(&self) -> Vec<(String, u16)> {
+        let last_times = self.last_button_time.lock().unwrap();
+        let now = Instant::now();
+
+        let mut codes: Vec<(String, u16)> = last_times
+            .iter()
+            .filter(|((device_name, code), &last_time)| {
+                let debounce_time = debounce_time_for(
+                    &self.debounce_overrides,
+                    self.debounce_time,
+                    device_name,
+                    *code,
+                );
+                now.duration_since(last_time) < debounce_time
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        codes.sort_unstable();
+        codes
     }
-}
 
-impl Drop for DevicePool {
-    fn drop(&mut self) {
-        let rt = tokio::runtime::Handle::try_current();
-        if let Ok(handle) = rt {
-            handle.spawn(async move {
-                // Cannot call self.stop() here as we've moved self
-            });
+    /// Builds the device pool state from the provided device descriptions.
+    ///
+    /// This method initializes the input register with the device states and
+    /// populates the devices vector with the given device descriptions.
+    ///
+    /// # Arguments
+    /// * `descriptions` - The parsed device descriptions to register.
+    ///
+    /// # Example
+    /// ```rust
+    /// let descriptions = DevicePool::load_descriptions(&["device1.toml".to_string()]);
+    /// let mut pool = DevicePool::new(Vec::new(), 0.1);
+    /// pool.build_state(descriptions);
+    /// ```
+    fn build_state(&mut self, descriptions: Vec<DeviceDescription>) {
+        self.devices.clear();
+        let mut input_register = self.input_register.lock().unwrap();
+        input_register.clear();
+
+        for desc in descriptions {
+            let device_name = desc.device_name.clone();
+            let state = desc.build_state();
+            input_register.insert(device_name, state);
+            self.devices.push(desc);
         }
+        self.debounce_overrides = Arc::new(
+            self.devices
+                .iter()
+                .flat_map(|desc| {
+                    desc.buttons.iter().chain(&desc.hats).filter_map(|item| {
+                        let debounce = Duration::from_millis(item.debounce_ms?);
+                        Some(((desc.device_name.clone(), item.code), debounce))
+                    })
+                })
+                .collect(),
+        );
+        // Factors outside (0, 1) would freeze or overshoot the axis, they are ignored
+        self.axis_smoothing = Arc::new(
+            self.devices
+                .iter()
+                .flat_map(|desc| {
+                    desc.axes.iter().filter_map(|item| {
+                        let alpha = item
+                            .smoothing
+                            .filter(|alpha| *alpha > 0.0 && *alpha < 1.0)?;
+                        Some(((desc.device_name.clone(), item.code), alpha))
+                    })
+                })
+                .collect(),
+        );
+        self.axis_buttons = Arc::new(
+            self.devices
+                .iter()
+                .flat_map(|desc| {
+                    desc.axes.iter().filter_map(|item| {
+                        let threshold = item.button_threshold.unwrap_or(DEFAULT_BUTTON_THRESHOLD);
+                        Some((
+                            (desc.device_name.clone(), item.code),
+                            (item.button_code?, threshold),
+                        ))
+                    })
+                })
+                .collect(),
+        );
+        self.register_version.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Resets the input register to the initial state based on the device descriptions.
+    ///
+    /// This method initializes the input register with the default states of all devices
+    /// defined in the device descriptions. It also updates the last input register
+    /// to match the current input register state.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// pool.reset_input_register();
+    /// ```
+    fn reset_input_register(&self) {
+        let mut input_register = self.input_register.lock().unwrap();
+        let mut last_input_register = self.last_input_register.lock().unwrap();
+
+        for desc in &self.devices {
+            let state = desc.build_state();
+            input_register.insert(desc.device_name.clone(), state.clone());
+        }
+        *last_input_register = input_register.clone();
+        self.smoothing_targets.lock().unwrap().clear();
+        self.register_version.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Copies the axis values of `snapshot` back into the input registers.
+    fn restore_axes(&self, snapshot: HashMap<String, JoystickState>) {
+        let mut input_register = self.input_register.lock().unwrap();
+        let mut last_input_register = self.last_input_register.lock().unwrap();
+
+        for (device_name, saved) in snapshot {
+            for register in [&mut *input_register, &mut *last_input_register] {
+                if let Some(state) = register.get_mut(&device_name) {
+                    state.axes.extend(&saved.axes);
+                    state.raw_axes.extend(&saved.raw_axes);
+                }
+            }
+        }
+        self.register_version.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Resets the trigger register by clearing all button and hat states.
+    ///
+    /// This method iterates through the input register and sets all button and hat values to zero,
+    /// effectively resetting the trigger states for all devices, or only for the devices
+    /// listed in `devices`. The `held` levels are left untouched.
+    ///
+    /// # Example
+    /// ```rust
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// pool.reset_trigger_register(None);
+    /// ```
+    fn reset_trigger_register(&self, devices: Option<&[String]>) {
+        let mut input_register = self.input_register.lock().unwrap();
+        for (device_name, input_data) in input_register.iter_mut() {
+            if devices.is_some_and(|devices| !devices.contains(device_name)) {
+                continue;
+            }
+            for (_button_key, button_value) in input_data.buttons.iter_mut() {
+                *button_value = 0;
+            }
+            for (_hat_key, hat_value) in input_data.hats.iter_mut() {
+                *hat_value = 0;
+            }
+            for (_button_key, pressed) in input_data.pressed_this_cycle.iter_mut() {
+                *pressed = false;
+            }
+            for (_axis_key, crossing) in input_data.axis_crossings.iter_mut() {
+                *crossing = 0;
+            }
+        }
+        self.register_version.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Checks the currently connected devices against the input register.
+    ///
+    /// This method fetches the list of connected joysticks and compares them
+    /// with the loaded descriptions. A merged description is connected once every
+    /// one of its members is.
+    ///
+    /// # Returns
+    /// A vector of strings containing the names of described devices that are currently
+    /// connected, in description order.
+    /// # Example
+    /// ```rust
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// let connected_devices = pool.check_devices();
+    /// ```
+    fn check_devices(&self) -> Vec<String> {
+        Self::connected_descriptions(&self.devices, &fetch_connected_joysticks(true))
+    }
+
+    /// Returns the names of the descriptions whose devices are all in `connected`.
+    ///
+    /// A merged description needs a device of each of its member names, any other
+    /// description a device it matches (see [`DeviceDescription::match_rank`]). Names
+    /// are compared according to the `match_mode` of the description.
+    fn connected_descriptions(
+        descriptions: &[DeviceDescription],
+        connected: &[JoystickInfo],
+    ) -> Vec<String> {
+        descriptions
+            .iter()
+            .filter(|desc| {
+                if desc.members.is_empty() {
+                    return connected.iter().any(|device| desc.matches(device));
+                }
+                desc.members.iter().all(|name| {
+                    connected
+                        .iter()
+                        .any(|device| desc.match_mode.matches(name, &device.name))
+                })
+            })
+            .map(|desc| desc.device_name.clone())
+            .collect()
+    }
+
+    /// Starts monitoring the connected devices for input changes.
+    ///
+    /// This method initializes the monitoring tasks for each connected joystick that
+    /// matches a loaded description, allowing them to report input states asynchronously.
+    /// It sets up a shutdown channel to gracefully stop monitoring when needed.
+    /// The running flag is checked and set under one lock, so a pool that is already
+    /// monitoring never gets a second set of monitor tasks.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// pool.start_monitoring().await;
+    /// ```
+    async fn start_monitoring(&mut self) {
+        {
+            let mut running = self.running.lock().unwrap();
+            if *running {
+                return;
+            }
+            *running = true;
+        }
+
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        self.shutdown_tx = Some(shutdown_tx);
+        let (cancel_tx, cancel_rx) = watch::channel(false);
+        self.cancel_tx = Some(cancel_tx);
+
+        let context = MonitorContext {
+            input_register: Arc::clone(&self.input_register),
+            register_version: Arc::clone(&self.register_version),
+            register_changed: Arc::clone(&self.register_changed),
+            last_button_time: Arc::clone(&self.last_button_time),
+            button_press_time: Arc::clone(&self.button_press_time),
+            button_callbacks: Arc::clone(&self.button_callbacks),
+            callback_tx: Some(spawn_callback_worker()),
+            device_status: Arc::clone(&self.device_status),
+            paused: Arc::clone(&self.paused),
+            shutdown: shutdown_rx.clone(),
+            cancel: cancel_rx,
+            debounce_time: self.debounce_time,
+            debounce_overrides: Arc::clone(&self.debounce_overrides),
+            axis_smoothing: Arc::clone(&self.axis_smoothing),
+            smoothing_targets: Arc::clone(&self.smoothing_targets),
+            axis_buttons: Arc::clone(&self.axis_buttons),
+            max_open_retries: self.max_open_retries,
+            open_retry_delay: self.open_retry_delay,
+            activity_tx: self.activity_tx.clone(),
+            event_log: Arc::clone(&self.event_log),
+            live_monitors: Arc::clone(&self.live_monitors),
+        };
+        let descriptions = self.devices.clone();
+
+        let hotplug = self.hotplug;
+        let hotplug_tx = self.hotplug_tx.clone();
+
+        let supervisor = async move {
+            // Monitor tasks keyed by device path
+            let mut tasks: HashMap<String, TrackedMonitor> = HashMap::new();
+
+            // The descriptions already pick the devices to monitor, so every device is
+            // enumerated and a described one is never hidden by the joystick filter.
+            for (device_info, description) in
+                Self::described_devices(fetch_connected_joysticks(true), &descriptions)
+            {
+                Self::spawn_monitor(&mut tasks, &context, device_info, description);
+            }
+
+            if hotplug {
+                loop {
+                    tokio::select! {
+                        _ = sleep(HOTPLUG_INTERVAL) => {
+                            Self::rescan_devices(
+                                &mut tasks,
+                                &descriptions,
+                                &context,
+                                hotplug_tx.as_ref(),
+                            );
+                        }
+                        _ = shutdown_rx.wait_for(|&stop| stop) => break,
+                    }
+                }
+            } else {
+                let _ = shutdown_rx.wait_for(|&stop| stop).await;
+            }
+
+            // Every monitor stops on its own once the shutdown signal is sent
+            // or the sender is dropped, wait for them to finish.
+            for (_, monitor) in tasks {
+                let _ = monitor.task.await;
+            }
+        };
+        // The monitors are spawned from the supervisor and end up on the same runtime
+        self.supervisor = Some(match &self.runtime {
+            Some(runtime) => runtime.spawn(supervisor),
+            None => tokio::spawn(supervisor),
+        });
+    }
+
+    /// Pairs the connected devices with their descriptions, dropping undescribed ones.
+    ///
+    /// Devices without a description have no entry in the input register, so
+    /// monitoring them would only waste work.
+    ///
+    /// # Arguments
+    /// * `connected` - The devices that are connected right now.
+    /// * `descriptions` - The known device descriptions.
+    ///
+    /// # Returns
+    /// The described devices together with their description, in enumeration order.
+    fn described_devices(
+        connected: Vec<JoystickInfo>,
+        descriptions: &[DeviceDescription],
+    ) -> Vec<(JoystickInfo, DeviceDescription)> {
+        connected
+            .into_iter()
+            .filter_map(|device_info| {
+                let description = find_description(descriptions, &device_info)?.clone();
+                Some((device_info, description))
+            })
+            .collect()
+    }
+
+    /// Spawns a supervised monitor task for `device_info` and tracks it in `tasks`.
+    fn spawn_monitor(
+        tasks: &mut HashMap<String, TrackedMonitor>,
+        context: &MonitorContext,
+        device_info: JoystickInfo,
+        description: DeviceDescription,
+    ) {
+        let description_name = description.device_name.clone();
+        let monitor = Self::monitor_device(device_info.path.clone(), description, context.clone());
+        let task = Self::spawn_supervised(context.clone(), description_name, monitor);
+        tasks.insert(
+            device_info.path,
+            TrackedMonitor {
+                name: device_info.name,
+                task,
+                restarts: 0,
+                retry_at: None,
+            },
+        );
+    }
+
+    /// Re-enumerates the connected devices and reconciles them with the tracked monitors.
+    fn rescan_devices(
+        tasks: &mut HashMap<String, TrackedMonitor>,
+        descriptions: &[DeviceDescription],
+        context: &MonitorContext,
+        hotplug_tx: Option<&mpsc::UnboundedSender<HotplugEvent>>,
+    ) {
+        Self::reconcile_devices(
+            tasks,
+            &fetch_connected_joysticks(true),
+            descriptions,
+            context,
+            hotplug_tx,
+        );
+    }
+
+    /// Reconciles the tracked monitors with the `connected` devices.
+    ///
+    /// Newly connected devices matching a description get a monitor task, the monitors
+    /// of removed devices are dropped, whether they were still running or had already
+    /// exited (e.g. on `ENODEV`). Both changes are reported through `hotplug_tx`. A
+    /// monitor that exited while its device stayed connected is restarted silently
+    /// after a delay that doubles with each restart, up to `MAX_RESPAWN_DELAY`.
+    fn reconcile_devices(
+        tasks: &mut HashMap<String, TrackedMonitor>,
+        connected: &[JoystickInfo],
+        descriptions: &[DeviceDescription],
+        context: &MonitorContext,
+        hotplug_tx: Option<&mpsc::UnboundedSender<HotplugEvent>>,
+    ) {
+        let tracked: HashMap<String, String> = tasks
+            .iter()
+            .map(|(path, monitor)| (path.clone(), monitor.name.clone()))
+            .collect();
+        let (appeared, disappeared) = Self::hotplug_changes(&tracked, connected, descriptions);
+
+        for (path, name) in disappeared {
+            if let Some(monitor) = tasks.remove(&path) {
+                monitor.task.abort();
+            }
+            info!("Device disconnected: {}", name);
+            if let Some(hotplug_tx) = hotplug_tx {
+                let _ = hotplug_tx.send(HotplugEvent::Disconnected(name));
+            }
+        }
+
+        for device_info in appeared {
+            let name = device_info.name.clone();
+            let Some(description) = find_description(descriptions, &device_info).cloned() else {
+                continue;
+            };
+            Self::spawn_monitor(tasks, context, device_info, description);
+            info!("Device connected: {}", name);
+            if let Some(hotplug_tx) = hotplug_tx {
+                let _ = hotplug_tx.send(HotplugEvent::Connected(name));
+            }
+        }
+
+        let now = Instant::now();
+        for device_info in connected {
+            let Some(monitor) = tasks.get_mut(&device_info.path) else {
+                continue;
+            };
+            if !monitor.task.is_finished() {
+                continue;
+            }
+            let restarts = monitor.restarts;
+            let retry_at = *monitor
+                .retry_at
+                .get_or_insert_with(|| now + respawn_delay(restarts));
+            if now < retry_at {
+                continue;
+            }
+            let Some(description) = find_description(descriptions, device_info).cloned() else {
+                continue;
+            };
+            info!("Restarting monitor of {}", device_info.name);
+            Self::spawn_monitor(tasks, context, device_info.clone(), description);
+            if let Some(monitor) = tasks.get_mut(&device_info.path) {
+                monitor.restarts = restarts.saturating_add(1);
+            }
+        }
+    }
+
+    /// Diffs the connected devices against the tracked ones.
+    ///
+    /// # Arguments
+    /// * `monitored` - The paths of the devices with a tracked monitor, running or not,
+    ///   mapped to their names.
+    /// * `connected` - The devices that are connected right now.
+    /// * `descriptions` - The known device descriptions.
+    ///
+    /// # Returns
+    /// The connected devices that match a description but have no tracked monitor, and
+    /// the `(path, name)` pairs of tracked devices that are no longer connected.
+    fn hotplug_changes(
+        monitored: &HashMap<String, String>,
+        connected: &[JoystickInfo],
+        descriptions: &[DeviceDescription],
+    ) -> (Vec<JoystickInfo>, Vec<(String, String)>) {
+        let appeared = connected
+            .iter()
+            .filter(|device| !monitored.contains_key(&device.path))
+            .filter(|device| find_description(descriptions, device).is_some())
+            .cloned()
+            .collect();
+        let mut disappeared: Vec<(String, String)> = monitored
+            .iter()
+            .filter(|(path, _)| !connected.iter().any(|device| &device.path == *path))
+            .map(|(path, name)| (path.clone(), name.clone()))
+            .collect();
+        disappeared.sort();
+        (appeared, disappeared)
+    }
+
+    /// Spawns a device monitor task that records a panic instead of dying silently.
+    ///
+    /// If the monitor panics, the panic is caught, the shared registers are released
+    /// from their poisoned state and the device status is updated so the crash shows
+    /// up in `device_report()` and `last_errors()`. The task counts as live in
+    /// `device_health()` until it exits. Cancelling the pool (see
+    /// [`DevicePool::stop`]) drops the monitor at its next await point.
+    ///
+    /// # Arguments
+    /// * `context` - The shared registers of the pool.
+    /// * `device_name` - The name of the device being monitored.
+    /// * `monitor` - The monitor future to run.
+    ///
+    /// # Returns
+    /// The handle of the spawned task.
+    fn spawn_supervised(
+        context: MonitorContext,
+        device_name: String,
+        monitor: impl Future<Output = ()> + Send + 'static,
+    ) -> JoinHandle<()> {
+        let live = LiveMonitor::new(&context.live_monitors, &device_name);
+        let mut cancel = context.cancel.clone();
+        let monitor = async move {
+            tokio::select! {
+                _ = monitor => {}
+                // A dropped sender never cancels, the monitor then runs to completion
+                Ok(_) = cancel.wait_for(|&cancel| cancel) => {}
+            }
+        };
+        tokio::spawn(async move {
+            let _live = live;
+            if let Err(payload) = AssertUnwindSafe(monitor).catch_unwind().await {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic".to_string());
+                error!("Monitor for {} panicked: {}", device_name, message);
+
+                context.clear_poison();
+                context.update_status(&device_name, |status| {
+                    status.monitored = false;
+                    status.last_error = Some(format!("Monitor task panicked: {}", message));
+                });
+            }
+        })
+    }
+
+    /// Stops monitoring the devices and cleans up resources.
+    ///
+    /// This method sets the running state to false, signaling all monitoring tasks to stop.
+    /// It also sends a shutdown signal through the channel if it exists and waits for the
+    /// supervisor, which in turn waits for every monitor, so no monitor of this run can
+    /// touch the registers once it returns. Stopping a stopped pool does nothing.
+    ///
+    /// With a `drain_timeout`, monitors still running when it expires are cancelled
+    /// and the supervisor is awaited again, which then only takes until each of them
+    /// reaches its next await point.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// pool.stop_monitoring(Some(Duration::from_secs(1))).await;
+    /// ```
+    async fn stop_monitoring(&mut self, drain_timeout: Option<Duration>) {
+        let was_running = std::mem::replace(&mut *self.running.lock().unwrap(), false);
+        if !was_running {
+            return;
+        }
+
+        if let Some(shutdown_tx) = self.shutdown_tx.take() {
+            let _ = shutdown_tx.send(true);
+        }
+        // Wake up pending fetches so they notice monitoring has stopped
+        self.register_changed.notify_waiters();
+
+        let cancel_tx = self.cancel_tx.take();
+        if let Some(mut supervisor) = self.supervisor.take() {
+            let drained = match drain_timeout {
+                Some(drain_timeout) => tokio::time::timeout(drain_timeout, &mut supervisor)
+                    .await
+                    .is_ok(),
+                None => {
+                    let _ = (&mut supervisor).await;
+                    true
+                }
+            };
+            if !drained {
+                warn!(
+                    "Monitor tasks did not stop within {:?}, cancelling them",
+                    drain_timeout.unwrap_or_default()
+                );
+                if let Some(cancel_tx) = &cancel_tx {
+                    let _ = cancel_tx.send(true);
+                }
+                let _ = supervisor.await;
+            }
+        }
+
+        let mut device_status = self.device_status.lock().unwrap();
+        for status in device_status.values_mut() {
+            status.monitored = false;
+        }
+    }
+
+    /// Monitors a single joystick device for input changes.
+    ///
+    /// This method awaits the event stream of the joystick and updates the input register
+    /// with the current axes, buttons, and hats as soon as the device reports them. It
+    /// implements debouncing logic to prevent rapid button press registrations, and keeps
+    /// the device status up to date so it shows up correctly in `device_report()`.
+    /// The loop ends when the pool sends its shutdown signal or the device fails, e.g.
+    /// with `ENODEV` after it was unplugged. A failed device is logged once and its
+    /// register entry is zeroed, so none of its buttons stay pressed. A device that
+    /// fails to open is retried as configured with [`DevicePool::set_open_retries`].
+    ///
+    /// There is no polling interval to tune: the task sleeps until the kernel delivers
+    /// events, so the update rate always follows the device's own report rate, whether
+    /// that is a 1000Hz flight stick or an idle controller that reports nothing.
+    ///
+    /// # Arguments
+    /// * `device_path` - The file path of the joystick device to monitor.
+    /// * `description` - The description of the device, providing its name, rotation
+    ///   and per-axis options.
+    /// * `context` - The shared registers, shutdown signal and debounce time of the pool.
+    ///
+    /// # Example
+    /// ```rust
+    /// let device_path = "/dev/input/js0".to_string();
+    /// let description = DeviceDescription::from_toml_rust("device1.toml")?;
+    /// DevicePool::monitor_device(device_path, description, context).await;
+    /// ```
+    async fn monitor_device(
+        device_path: String,
+        description: DeviceDescription,
+        context: MonitorContext,
+    ) {
+        let open = || {
+            Joystick::with_description(&device_path, &description)
+                .and_then(|joystick| joystick.into_event_stream())
+        };
+        Self::open_and_monitor(open, &description, &context).await;
+    }
+
+    /// Opens a device with `open`, retrying failures with exponential backoff, and
+    /// monitors it once it opened.
+    ///
+    /// A shutdown during a backoff delay ends the monitor without another attempt.
+    ///
+    /// # Arguments
+    /// * `open` - Opens the source of the device's states.
+    /// * `description` - The description of the device, providing its name and rotation.
+    /// * `context` - The shared registers, shutdown signal and retry settings of the pool.
+    async fn open_and_monitor<S: StateSource>(
+        mut open: impl FnMut() -> std::io::Result<S>,
+        description: &DeviceDescription,
+        context: &MonitorContext,
+    ) {
+        let device_name = &description.device_name;
+        let mut shutdown = context.shutdown.clone();
+        let mut retries = 0;
+        let result = loop {
+            match open() {
+                Ok(events) => break Ok(events),
+                Err(e) if retries < context.max_open_retries => {
+                    let delay = context
+                        .open_retry_delay
+                        .saturating_mul(2u32.saturating_pow(retries));
+                    retries += 1;
+                    warn!(
+                        "Failed to open {} (retry {} of {} in {:?}): {}",
+                        device_name, retries, context.max_open_retries, delay, e
+                    );
+                    tokio::select! {
+                        _ = sleep(delay) => {}
+                        _ = shutdown.wait_for(|&stop| stop) => break Err(e),
+                    }
+                }
+                Err(e) => break Err(e),
+            }
+        };
+
+        match result {
+            Ok(mut events) => Self::run_monitor(&mut events, description, context).await,
+            Err(e) => {
+                error!(
+                    "Failed to create joystick for {} after {} retries: {}",
+                    device_name, retries, e
+                );
+                context.update_status(device_name, |status| {
+                    status.monitored = false;
+                    status.last_error = Some(e.to_string());
+                });
+            }
+        }
+    }
+
+    /// Marks the device as monitored and applies its states until the monitor ends.
+    ///
+    /// The start and end of monitoring are logged at info level.
+    ///
+    /// # Arguments
+    /// * `events` - The source of the device's states.
+    /// * `description` - The description of the device, providing its name and rotation.
+    /// * `context` - The shared registers, shutdown signal and debounce time of the pool.
+    async fn run_monitor(
+        events: &mut impl StateSource,
+        description: &DeviceDescription,
+        context: &MonitorContext,
+    ) {
+        let device_name = &description.device_name;
+        info!("Started monitoring {}", device_name);
+        context.update_status(device_name, |status| {
+            *status = DeviceStatus {
+                monitored: true,
+                ..DeviceStatus::default()
+            };
+        });
+
+        Self::read_states(events, description, context).await;
+
+        context.update_status(device_name, |status| status.monitored = false);
+        info!("Stopped monitoring {}", device_name);
+    }
+
+    /// Applies every state read from `events` until shutdown or a read error.
+    ///
+    /// The codes the description ignores are dropped before a state is applied, so
+    /// phantom inputs never reach the register or wake up a fetch.
+    ///
+    /// # Arguments
+    /// * `events` - The source of the device's states.
+    /// * `description` - The description of the device, providing its name and rotation.
+    /// * `context` - The shared registers, shutdown signal and debounce time of the pool.
+    async fn read_states(
+        events: &mut impl StateSource,
+        description: &DeviceDescription,
+        context: &MonitorContext,
+    ) {
+        let device_name = &description.device_name;
+        let mut shutdown = context.shutdown.clone();
+        loop {
+            // A batch is awaited across smoothing ticks, so no half-read batch is lost
+            let result = {
+                let next_state = events.next_state();
+                tokio::pin!(next_state);
+                loop {
+                    let smoothing = !context.paused.load(Ordering::SeqCst)
+                        && context.smoothing_pending(device_name);
+                    tokio::select! {
+                        result = &mut next_state => break result,
+                        _ = sleep(SMOOTHING_TICK), if smoothing => {
+                            Self::advance_smoothing(context, device_name);
+                        }
+                        _ = shutdown.wait_for(|&stop| stop) => return,
+                    }
+                }
+            };
+            match result {
+                // Paused monitors keep reading, so no backlog builds up meanwhile
+                Ok(mut state) if context.paused.load(Ordering::SeqCst) => {
+                    description.strip_ignored(&mut state);
+                    Self::track_paused_levels(context, device_name, &state);
+                }
+                Ok(mut state) => {
+                    description.strip_ignored(&mut state);
+                    Self::apply_state(context, device_name, description.rotation, state)
+                }
+                Err(e) => {
+                    warn!("Failed to read from {}: {}", device_name, e);
+                    context.update_status(device_name, |status| {
+                        status.last_error = Some(e.to_string());
+                    });
+                    context.release_device(description);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Moves the smoothed axes of `device_name` one step on towards their latest reading.
+    ///
+    /// Devices do not repeat an unchanged reading, so without these steps a smoothed
+    /// axis would stop short of a position that is held still.
+    fn advance_smoothing(context: &MonitorContext, device_name: &str) {
+        let mut input_register = context.input_register.lock().unwrap();
+        let Some(input_data) = input_register.get_mut(device_name) else {
+            return;
+        };
+        let mut events = Vec::new();
+        context
+            .smoothing_targets
+            .lock()
+            .unwrap()
+            .retain(|(name, code), &mut (alpha, target)| {
+                if name != device_name {
+                    return true;
+                }
+                let previous = input_data.axes.get(code).copied().unwrap_or(target);
+                let value = smooth_step(alpha, previous, target);
+                record_crossing(input_data, *code, previous, value);
+                input_data.axes.insert(*code, value);
+                events.push(InputEvent::new(device_name, InputKind::Axis, *code, value));
+                value != target
+            });
+        if events.is_empty() {
+            return;
+        }
+
+        input_data.timestamp_us = monotonic_us();
+        context.log_events(events);
+        context.register_version.fetch_add(1, Ordering::SeqCst);
+        context.register_changed.notify_waiters();
+        let _ = context.activity_tx.send(device_name.to_string());
+    }
+
+    /// Keeps the undebounced button levels of a paused device in sync with the device.
+    ///
+    /// Only `held`, `button_hold_ms` and the press times follow the state, nothing is
+    /// logged and no fetch is woken up. A button released while paused thus no longer
+    /// reads as held after `resume()`, and its next press is a rising edge again.
+    ///
+    /// # Arguments
+    /// * `context` - The shared registers of the pool.
+    /// * `device_name` - The name of the device the state was read from.
+    /// * `state` - The state read while the pool was paused.
+    fn track_paused_levels(context: &MonitorContext, device_name: &str, state: &JoystickState) {
+        let mut input_register = context.input_register.lock().unwrap();
+        let Some(input_data) = input_register.get_mut(device_name) else {
+            return;
+        };
+        let mut press_times = context.button_press_time.lock().unwrap();
+        for (&code, &value) in &state.buttons {
+            let key = (device_name.to_string(), code);
+            input_data.held.insert(code, value);
+            let held = if value == 1 {
+                let pressed_at = *press_times.entry(key).or_insert_with(Instant::now);
+                pressed_at.elapsed().as_millis() as u64
+            } else {
+                press_times.remove(&key);
+                0
+            };
+            input_data.button_hold_ms.insert(code, held);
+        }
+    }
+
+    /// Merges a freshly read joystick state into the input register.
+    ///
+    /// Axes are rotated into their logical orientation and stored as-is, buttons and
+    /// hats go through the debounce filter. The `held` levels follow the reported
+    /// buttons without debouncing. A button reported as released but marked in
+    /// `pressed_this_cycle` was tapped within the batch and still counts as a press.
+    /// Axes declared with a `button_code` report their synthetic button as if the device
+    /// had, whenever the normalized axis value crosses the threshold. Non-empty states also mark the device as
+    /// receiving data.
+    ///
+    /// # Arguments
+    /// * `context` - The shared registers and debounce time of the pool.
+    /// * `device_name` - The name of the device the state was read from.
+    /// * `rotation` - The mounting rotation applied to the X/Y axes.
+    /// * `state` - The state returned by `Joystick::get_state`.
+    fn apply_state(
+        context: &MonitorContext,
+        device_name: &str,
+        rotation: AxisRotation,
+        mut state: JoystickState,
+    ) {
+        if state.is_empty() {
+            return;
+        }
+
+        let mut rising = Vec::new();
+        let mut pressed = Vec::new();
+        {
+            let mut input_register = context.input_register.lock().unwrap();
+
+            if let Some(input_data) = input_register.get_mut(device_name) {
+                let mut events = Vec::new();
+
+                // Synthesize axis buttons from the physical reading, so they go through
+                // the same edge, hold and debounce handling as real buttons
+                for (&code, &value) in &state.axes {
+                    let key = (device_name.to_string(), code);
+                    if let Some(&(button, threshold)) = context.axis_buttons.get(&key) {
+                        let level = u8::from(value >= threshold);
+                        if input_data.held.get(&button) != Some(&level) {
+                            state.buttons.entry(button).or_insert(level);
+                        }
+                    }
+                }
+
+                // Update axes, mapping them onto the logical orientation. Smoothed axes
+                // blend the new reading with the value stored in the register and keep
+                // the reading as their target, see `advance_smoothing`.
+                for (code, value) in state.axes {
+                    let alpha = context
+                        .axis_smoothing
+                        .get(&(device_name.to_string(), code))
+                        .copied();
+                    let (code, target) = rotation.apply(code, value);
+                    let previous = input_data.axes.get(&code).copied();
+                    let mut value = target;
+                    if let (Some(alpha), Some(previous)) = (alpha, previous) {
+                        value = smooth_step(alpha, previous, target);
+                        let key = (device_name.to_string(), code);
+                        let mut targets = context.smoothing_targets.lock().unwrap();
+                        if value == target {
+                            targets.remove(&key);
+                        } else {
+                            targets.insert(key, (alpha, target));
+                        }
+                    }
+                    if let Some(previous) = previous {
+                        record_crossing(input_data, code, previous, value);
+                    }
+                    input_data.axes.insert(code, value);
+                    events.push(InputEvent::new(device_name, InputKind::Axis, code, value));
+                }
+
+                // Raw axes stay keyed by their physical code
+                input_data.raw_axes.extend(state.raw_axes);
+                input_data.rel_axes.extend(state.rel_axes);
+                // Event times stay keyed by the physical code, like raw axes
+                input_data.event_time_us.extend(state.event_time_us);
+                input_data.held.extend(&state.buttons);
+
+                // Track hold durations from the undebounced button level
+                {
+                    let mut press_times = context.button_press_time.lock().unwrap();
+                    for (&code, &value) in &state.buttons {
+                        let key = (device_name.to_string(), code);
+                        if value == 0 && tapped(&state.pressed_this_cycle, code) {
+                            input_data.pressed_this_cycle.insert(code, true);
+                            rising.push(code);
+                        }
+                        let held = if value == 1 {
+                            if !press_times.contains_key(&key) {
+                                // Rising edge, kept until the next fetch
+                                input_data.pressed_this_cycle.insert(code, true);
+                                rising.push(code);
+                            }
+                            let pressed_at = *press_times.entry(key).or_insert_with(Instant::now);
+                            pressed_at.elapsed().as_millis() as u64
+                        } else {
+                            press_times.remove(&key);
+                            0
+                        };
+                        input_data.button_hold_ms.insert(code, held);
+                    }
+                }
+
+                // Update buttons with debouncing
+                for (&code, &value) in &state.buttons {
+                    // A tap sets the trigger, its release is left to the next fetch
+                    let level = value;
+                    let value = if tapped(&state.pressed_this_cycle, code) {
+                        1
+                    } else {
+                        value
+                    };
+                    if Self::should_update_input(
+                        device_name,
+                        code,
+                        value == 0,
+                        &context.last_button_time,
+                        context.debounce_for(device_name, code),
+                    ) {
+                        input_data.buttons.insert(code, value);
+                        // Callbacks only fire for presses the debounce filter let through
+                        if value == 1 && rising.contains(&code) {
+                            pressed.push(code);
+                        }
+                        // The log sees both halves of a tap
+                        events.push(InputEvent::new(
+                            device_name,
+                            InputKind::Button,
+                            code,
+                            f32::from(value),
+                        ));
+                        if level != value {
+                            events.push(InputEvent::new(device_name, InputKind::Button, code, 0.0));
+                        }
+                    }
+                }
+
+                // Update hats with debouncing
+                for (code, value) in state.hats {
+                    if Self::should_update_input(
+                        device_name,
+                        code,
+                        value == 0,
+                        &context.last_button_time,
+                        context.debounce_for(device_name, code),
+                    ) {
+                        input_data.hats.insert(code, value);
+                        events.push(InputEvent::new(
+                            device_name,
+                            InputKind::Hat,
+                            code,
+                            f32::from(value),
+                        ));
+                    }
+                }
+
+                input_data.timestamp_us = monotonic_us();
+                context.log_events(events);
+                context.register_version.fetch_add(1, Ordering::SeqCst);
+                context.register_changed.notify_waiters();
+                let _ = context.activity_tx.send(device_name.to_string());
+            }
+        }
+
+        Self::dispatch_button_callbacks(context, device_name, pressed);
+
+        context.update_status(device_name, |status| {
+            status.last_input = Some(Instant::now());
+        });
+    }
+
+    /// Runs the callbacks registered for the `pressed` buttons of `device_name`.
+    ///
+    /// The callbacks are handed to the callback worker of the pool, or run inline when
+    /// there is none (or it is gone).
+    fn dispatch_button_callbacks(context: &MonitorContext, device_name: &str, pressed: Vec<u16>) {
+        let calls: Vec<(u16, ButtonCallback)> = {
+            let callbacks = context.button_callbacks.lock().unwrap();
+            pressed
+                .into_iter()
+                .flat_map(|code| {
+                    callbacks
+                        .get(&(device_name.to_string(), code))
+                        .into_iter()
+                        .flatten()
+                        .map(move |callback| (code, Arc::clone(callback)))
+                })
+                .collect()
+        };
+        if calls.is_empty() {
+            return;
+        }
+
+        let device_name = device_name.to_string();
+        let run = move || {
+            for (code, callback) in calls {
+                callback(&device_name, code);
+            }
+        };
+        let run: CallbackJob = Box::new(run);
+        match &context.callback_tx {
+            Some(callback_tx) => {
+                if let Err(std::sync::mpsc::SendError(run)) = callback_tx.send(run) {
+                    run();
+                }
+            }
+            None => run(),
+        }
+    }
+
+    /// Determines if an input should be updated based on the debounce time.
+    ///
+    /// This method checks the last time a button was pressed and compares it
+    /// with the current time. If the time since the last press is less than the
+    /// debounce time, it returns false, indicating that the input should not be updated.
+    /// Otherwise, it updates the last pressed time and returns true. Press times are
+    /// tracked per device, so devices sharing a code never debounce each other.
+    ///
+    /// Only presses are debounced. A release always goes through and leaves the press
+    /// time alone, so a quick tap can never leave its button stuck pressed.
+    ///
+    /// # Arguments
+    /// * `device_name` - The name of the device the input belongs to.
+    /// * `code` - The code of the button or hat being checked.
+    /// * `released` - Whether the new value is the released (zero) value.
+    /// * `last_button_time` - A shared reference to the last button press times.
+    /// * `debounce_time` - The duration to wait before allowing another button press registration.
+    ///
+    /// # Returns
+    /// A boolean indicating whether the input should be updated (true) or ignored (false).
+    fn should_update_input(
+        device_name: &str,
+        code: u16,
+        released: bool,
+        last_button_time: &Arc<Mutex<HashMap<(String, u16), Instant>>>,
+        debounce_time: Duration,
+    ) -> bool {
+        if released {
+            return true;
+        }
+
+        let mut last_times = last_button_time.lock().unwrap();
+        let now = Instant::now();
+        let key = (device_name.to_string(), code);
+
+        if let Some(&last_time) = last_times.get(&key) {
+            if now.duration_since(last_time) < debounce_time {
+                return false;
+            }
+        }
+
+        last_times.insert(key, now);
+        true
+    }
+
+    /// Starts monitoring the devices for input changes.
+    ///
+    /// This method checks if the device pool is already running. If not, it starts monitoring
+    /// the devices by calling `start_monitoring()`. It returns a vector of device names that are
+    /// currently connected and registered in the input register.
+    /// Unlike [`DevicePool::reset`], the input registers and the debounce and hold
+    /// bookkeeping are left as they are.
+    ///
+    /// # Returns
+    /// A vector of strings containing the names of devices that are currently connected
+    /// and registered in the input register.
+    /// # Example
+    /// ```rust
+    /// let mut pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// let connected_devices = pool.start().await;
+    /// ```
+    pub async fn start(&mut self) -> Vec<String> {
+        self.start_monitoring().await;
+        self.check_devices()
+    }
+
+    /// Stops monitoring the devices.
+    ///
+    /// Signals every monitor task to shut down and waits until monitoring has stopped.
+    /// The input registers keep their last values, see [`DevicePool::fetch_last`].
+    ///
+    /// # Arguments
+    /// * `drain_timeout` - How long to wait for the monitors to finish on their own,
+    ///   `None` to wait as long as it takes. Monitors still running afterwards are
+    ///   cancelled. Either way no monitor writes to the registers once this returns.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// pool.stop(Some(Duration::from_millis(500))).await;
+    /// ```
+    pub async fn stop(&mut self, drain_timeout: Option<Duration>) {
+        self.stop_monitoring(drain_timeout).await;
+    }
+}
+
+/// Blends `target` into `previous` with the smoothing factor `alpha`, snapping onto
+/// `target` once it is within `SMOOTHING_SETTLED`.
+fn smooth_step(alpha: f32, previous: f32, target: f32) -> f32 {
+    let value = alpha * target + (1.0 - alpha) * previous;
+    if (target - value).abs() < SMOOTHING_SETTLED {
+        target
+    } else {
+        value
+    }
+}
+
+/// Records a sign change of axis `code` from `previous` to `value` if it tracks crossings.
+fn record_crossing(input_data: &mut JoystickState, code: u16, previous: f32, value: f32) {
+    if let Some(crossing) = input_data.axis_crossings.get_mut(&code) {
+        // Only a sign change counts, the latest crossing wins
+        if previous < 0.0 && value > 0.0 {
+            *crossing = 1;
+        } else if previous > 0.0 && value < 0.0 {
+            *crossing = -1;
+        }
+    }
+}
+
+/// Starts the thread that runs button callbacks one batch after the other.
+///
+/// The thread ends once every sender, i.e. every monitor context of the run, is gone.
+fn spawn_callback_worker() -> std::sync::mpsc::Sender<CallbackJob> {
+    let (callback_tx, callback_rx) = std::sync::mpsc::channel::<CallbackJob>();
+    let spawned = std::thread::Builder::new()
+        .name("fly_stick-callbacks".to_string())
+        .spawn(move || {
+            for job in callback_rx {
+                job();
+            }
+        });
+    if let Err(e) = spawned {
+        // The jobs then run inline, see `dispatch_button_callbacks`
+        error!("Failed to start the button callback worker: {}", e);
+    }
+    callback_tx
+}
+
+/// Returns how long to wait before restarting a monitor that was restarted `restarts` times.
+fn respawn_delay(restarts: u32) -> Duration {
+    HOTPLUG_INTERVAL
+        .saturating_mul(2u32.saturating_pow(restarts))
+        .min(MAX_RESPAWN_DELAY)
+}
+
+/// Looks up the `debounce_ms` override of `code` on `device_name`, falling back to `default`.
+fn debounce_time_for(
+    overrides: &DebounceOverrides,
+    default: Duration,
+    device_name: &str,
+    code: u16,
+) -> Duration {
+    overrides
+        .get(&(device_name.to_string(), code))
+        .copied()
+        .unwrap_or(default)
+}
+
+/// Whether `code` was pressed during a batch, given the batch's `pressed_this_cycle`.
+fn tapped(pressed_this_cycle: &HashMap<u16, bool>, code: u16) -> bool {
+    pressed_this_cycle.get(&code) == Some(&true)
+}
+
+/// Finds the description a connected device should be monitored with.
+///
+/// The most specific match wins, so descriptions matching the device by `uniq`/`phys`
+/// take precedence over those matching its USB IDs, which take precedence over those
+/// matching its name. Among equal matches the first description wins. Every member of
+/// a merged description matches the merged description, so each member gets its own
+/// monitor writing into the shared register entry.
+fn find_description<'a>(
+    descriptions: &'a [DeviceDescription],
+    device: &JoystickInfo,
+) -> Option<&'a DeviceDescription> {
+    let mut best: Option<(u8, &DeviceDescription)> = None;
+    for desc in descriptions {
+        if let Some(rank) = desc.match_rank(device) {
+            if best.is_none_or(|(best_rank, _)| rank > best_rank) {
+                best = Some((rank, desc));
+            }
+        }
+    }
+    best.map(|(_, desc)| desc)
+}
+
+/// Returns true if both maps hold the same devices with states equal up to
+/// `axis_epsilon`, see [`JoystickState::approx_eq`].
+fn states_approx_eq(
+    states: &HashMap<String, JoystickState>,
+    other: &HashMap<String, JoystickState>,
+    axis_epsilon: f32,
+) -> bool {
+    states.len() == other.len()
+        && states.iter().all(|(device_name, state)| {
+            other
+                .get(device_name)
+                .is_some_and(|other| state.approx_eq(other, axis_epsilon))
+        })
+}
+
+/// Copies the entries of `states` whose device is listed in `filter`.
+///
+/// Only the selected states are cloned, so a filtered fetch does not copy the whole
+/// register.
+fn select_devices(
+    states: &HashMap<String, JoystickState>,
+    filter: Option<&[String]>,
+) -> HashMap<String, JoystickState> {
+    match filter {
+        Some(filter) => states
+            .iter()
+            .filter(|(device_name, _)| filter.contains(device_name))
+            .map(|(device_name, state)| (device_name.clone(), state.clone()))
+            .collect(),
+        None => states.clone(),
+    }
+}
+
+/// Stops monitoring when the pool goes away.
+///
+/// The shutdown signal is sent synchronously, so the monitor tasks end on their own
+/// instead of outliving the pool.
+impl Drop for DevicePool {
+    fn drop(&mut self) {
+        *self.running.lock().unwrap_or_else(|e| e.into_inner()) = false;
+        if let Some(shutdown_tx) = self.shutdown_tx.take() {
+            let _ = shutdown_tx.send(true);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inner::description::{DeviceItem, NameMatch};
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_from_descriptions_registers_devices() {
+        let stick = DeviceDescription::from_toml_str_rust(
+            r#"
+device_name = "Stick"
+
+[[axes]]
+code = 0
+
+[[buttons]]
+code = 288
+"#,
+        )
+        .unwrap();
+        let throttle = DeviceDescription::from_json_str_rust(
+            r#"{"device_name": "Throttle", "axes": [{"code": 2}]}"#,
+        )
+        .unwrap();
+
+        let pool = DevicePool::from_descriptions(vec![stick, throttle], 0.1, false);
+        let input_register = pool.input_register.lock().unwrap();
+
+        assert_eq!(pool.devices.len(), 2);
+        assert_eq!(input_register.len(), 2);
+        assert_eq!(input_register["Stick"].buttons.get(&288), Some(&0));
+        assert_eq!(input_register["Throttle"].axes.get(&2), Some(&0.0));
+    }
+
+    /// Runs with `--no-default-features`, where nothing links against Python.
+    #[cfg(not(feature = "python"))]
+    #[tokio::test]
+    async fn test_rust_api_works_without_python() {
+        assert!(Joystick::new("/dev/input/nonexistent").is_err());
+
+        let mut pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        pool.start().await;
+        assert!(pool.is_running());
+        assert_eq!(pool.fetch_nowait().unwrap()["Stick"].axes[&0], 0.0);
+
+        pool.stop(None).await;
+        assert!(!pool.is_running());
+    }
+
+    #[test]
+    fn test_load_descriptions_skips_missing_files() {
+        let descriptions = DevicePool::load_descriptions(&["nonexistent_file.toml".to_string()]);
+        assert!(descriptions.is_empty());
+    }
+
+    #[test]
+    fn test_load_errors_report_malformed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("good.toml");
+        let bad = dir.path().join("bad.toml");
+        std::fs::write(&good, "device_name = \"Good Stick\"\n").unwrap();
+        std::fs::write(&bad, "device_name = \"Bad Stick\n").unwrap();
+        let files = vec![
+            good.to_string_lossy().into_owned(),
+            bad.to_string_lossy().into_owned(),
+        ];
+
+        let pool = DevicePool::new(files.clone(), 0.1);
+
+        assert_eq!(pool.monitored_devices(), vec!["Good Stick".to_string()]);
+        let errors = pool.load_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, files[1]);
+        assert!(!errors[0].1.is_empty());
+    }
+
+    fn test_description(name: &str) -> DeviceDescription {
+        DeviceDescription::from_toml_str_rust(&format!(
+            r#"
+device_name = "{}"
+
+[[axes]]
+code = 0
+
+[[buttons]]
+code = 288
+"#,
+            name
+        ))
+        .unwrap()
+    }
+
+    fn test_context(pool: &DevicePool) -> MonitorContext {
+        MonitorContext {
+            input_register: Arc::clone(&pool.input_register),
+            register_version: Arc::clone(&pool.register_version),
+            register_changed: Arc::clone(&pool.register_changed),
+            last_button_time: Arc::clone(&pool.last_button_time),
+            button_press_time: Arc::clone(&pool.button_press_time),
+            button_callbacks: Arc::clone(&pool.button_callbacks),
+            callback_tx: None,
+            device_status: Arc::clone(&pool.device_status),
+            paused: Arc::clone(&pool.paused),
+            shutdown: watch::channel(false).1,
+            cancel: watch::channel(false).1,
+            debounce_time: pool.debounce_time,
+            debounce_overrides: Arc::clone(&pool.debounce_overrides),
+            axis_smoothing: Arc::clone(&pool.axis_smoothing),
+            smoothing_targets: Arc::clone(&pool.smoothing_targets),
+            axis_buttons: Arc::clone(&pool.axis_buttons),
+            max_open_retries: pool.max_open_retries,
+            open_retry_delay: pool.open_retry_delay,
+            activity_tx: pool.activity_tx.clone(),
+            event_log: Arc::clone(&pool.event_log),
+            live_monitors: Arc::clone(&pool.live_monitors),
+        }
+    }
+
+    #[test]
+    fn test_device_report_defaults() {
+        let pool = DevicePool::from_descriptions(
+            vec![test_description("Stick"), test_description("Throttle")],
+            0.1,
+            false,
+        );
+
+        let report = pool.device_report();
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].device_name, "Stick");
+        assert_eq!(report[1].device_name, "Throttle");
+        for entry in &report {
+            assert!(!entry.connected);
+            assert!(!entry.monitored);
+            assert!(!entry.receiving_data);
+            assert_eq!(entry.last_error, None);
+        }
+    }
+
+    #[test]
+    fn test_device_report_tracks_activity_and_errors() {
+        let pool = DevicePool::from_descriptions(
+            vec![test_description("Stick"), test_description("Throttle")],
+            0.1,
+            false,
+        );
+        let context = test_context(&pool);
+
+        context.update_status("Stick", |status| status.monitored = true);
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.5);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+
+        context.update_status("Throttle", |status| {
+            status.last_error = Some("Permission denied".to_string());
+        });
+
+        let report = pool.device_report();
+        assert!(report[0].monitored);
+        assert!(report[0].receiving_data);
+        assert_eq!(report[0].last_error, None);
+        assert!(!report[1].monitored);
+        assert!(!report[1].receiving_data);
+        assert_eq!(report[1].last_error.as_deref(), Some("Permission denied"));
+    }
+
+    #[test]
+    fn test_apply_state_merges_raw_axes() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        let context = test_context(&pool);
+
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.5);
+        state.raw_axes.insert(0, 768);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg90, state);
+
+        let input_register = pool.input_register.lock().unwrap();
+        assert_eq!(input_register["Stick"].axes.get(&1), Some(&-0.5));
+        assert_eq!(input_register["Stick"].raw_axes.get(&0), Some(&768));
+    }
+
+    #[test]
+    fn test_apply_state_bumps_register_version() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        let context = test_context(&pool);
+        let before = pool.register_version.load(Ordering::SeqCst);
+
+        let mut state = JoystickState::new();
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+        assert_eq!(pool.register_version.load(Ordering::SeqCst), before + 1);
+
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, JoystickState::new());
+        assert_eq!(pool.register_version.load(Ordering::SeqCst), before + 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_returns_promptly_after_change() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 10.0, false);
+        *pool.running.lock().unwrap() = true;
+        pool.fetch_nowait().unwrap();
+
+        let context = test_context(&pool);
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(50)).await;
+            let mut state = JoystickState::new();
+            state.buttons.insert(288, 1);
+            DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+        });
+
+        let start = Instant::now();
+        let state = pool
+            .fetch(Some(Duration::from_secs(5)), None)
+            .await
+            .unwrap();
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(state["Stick"].buttons.get(&288), Some(&1));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_times_out_without_changes() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        *pool.running.lock().unwrap() = true;
+        pool.fetch_nowait().unwrap();
+
+        let result = pool.fetch(Some(Duration::from_millis(50)), None).await;
+        assert!(matches!(result, Err(DevicePoolError::Timeout)));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_or_current_returns_state_on_timeout() {
+        let pool = DevicePool::from_descriptions(
+            vec![test_description("Stick"), test_description("Throttle")],
+            0.0,
+            false,
+        );
+        *pool.running.lock().unwrap() = true;
+        let context = test_context(&pool);
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.5);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+        pool.fetch_nowait().unwrap();
+
+        let mut state = JoystickState::new();
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Throttle", AxisRotation::Deg0, state);
+
+        let start = Instant::now();
+        let states = pool
+            .fetch_or_current(
+                Some(Duration::from_millis(50)),
+                Some(vec!["Stick".to_string()]),
+            )
+            .await
+            .unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        assert_eq!(states.len(), 1);
+        assert_eq!(states["Stick"].axes[&0], 0.5);
+
+        // The filtered-out change is still pending
+        let pending = pool.fetch_nowait_changed().unwrap().unwrap();
+        assert_eq!(pending["Throttle"].buttons[&288], 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_ignores_filtered_out_devices() {
+        let pool = DevicePool::from_descriptions(
+            vec![test_description("Stick"), test_description("Throttle")],
+            0.0,
+            false,
+        );
+        *pool.running.lock().unwrap() = true;
+        pool.fetch_nowait().unwrap();
+        let context = test_context(&pool);
+
+        let mut state = JoystickState::new();
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Throttle", AxisRotation::Deg0, state);
+
+        let filter = Some(vec!["Stick".to_string()]);
+        let result = pool
+            .fetch(Some(Duration::from_millis(50)), filter.clone())
+            .await;
+        assert!(matches!(result, Err(DevicePoolError::Timeout)));
+
+        let mut state = JoystickState::new();
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+        let states = pool
+            .fetch(Some(Duration::from_millis(50)), filter)
+            .await
+            .unwrap();
+        assert_eq!(states.keys().collect::<Vec<_>>(), vec!["Stick"]);
+
+        // The throttle press is still pending for an unfiltered fetch
+        let states = pool
+            .fetch(Some(Duration::from_millis(50)), None)
+            .await
+            .unwrap();
+        assert_eq!(states["Throttle"].buttons.get(&288), Some(&1));
+    }
+
+    #[tokio::test]
+    async fn test_is_running_follows_start_and_stop() {
+        let mut pool = DevicePool::new(Vec::new(), 0.1);
+        assert!(!pool.is_running());
+
+        pool.start_monitoring().await;
+        assert!(pool.is_running());
+
+        pool.stop(None).await;
+        assert!(!pool.is_running());
+    }
+
+    #[tokio::test]
+    async fn test_start_keeps_existing_state() {
+        let mut pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        let context = test_context(&pool);
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.25);
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+
+        pool.start().await;
+        assert!(pool.is_running());
+        let state = pool.fetch_nowait().unwrap();
+        assert_eq!(state["Stick"].axes[&0], 0.25);
+        assert_eq!(state["Stick"].buttons[&288], 1);
+        assert!(pool
+            .button_press_time
+            .lock()
+            .unwrap()
+            .contains_key(&("Stick".to_string(), 288)));
+
+        pool.stop(None).await;
+        assert!(!pool.is_running());
+    }
+
+    #[tokio::test]
+    async fn test_monitored_devices_match_descriptions() {
+        let mut pool = DevicePool::from_descriptions(
+            vec![test_description("Throttle"), test_description("Stick")],
+            0.1,
+            false,
+        );
+        assert_eq!(pool.monitored_devices(), vec!["Throttle", "Stick"]);
+
+        pool.reset().await;
+        assert!(pool.is_running());
+        assert_eq!(pool.monitored_devices(), vec!["Throttle", "Stick"]);
+        pool.stop(None).await;
+        assert!(!pool.is_running());
+    }
+
+    #[tokio::test]
+    async fn test_button_callback_fires_once_per_press() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        let context = test_context(&pool);
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&calls);
+        pool.on_button(
+            "Stick",
+            288,
+            Arc::new(move |device_name: &str, code| {
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push((device_name.to_string(), code));
+            }),
+        );
+        let button = |value| {
+            let mut state = JoystickState::new();
+            state.buttons.insert(288, value);
+            state
+        };
+
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, button(1));
+        // Held and other devices do not fire again
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, button(1));
+        DevicePool::apply_state(&context, "Throttle", AxisRotation::Deg0, button(1));
+
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while calls.lock().unwrap().is_empty() {
+                sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .unwrap();
+        sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(*calls.lock().unwrap(), vec![("Stick".to_string(), 288)]);
+    }
+
+    #[test]
+    fn test_fetch_last_works_without_monitoring() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        let context = test_context(&pool);
+
+        let mut state = JoystickState::new();
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+
+        assert!(matches!(
+            pool.fetch_nowait(),
+            Err(DevicePoolError::NotRunning)
+        ));
+        let snapshot = pool.fetch_last();
+        assert_eq!(snapshot["Stick"].buttons.get(&288), Some(&1));
+        // Triggers are left alone, so the press is still visible
+        assert_eq!(pool.fetch_last()["Stick"].buttons.get(&288), Some(&1));
+    }
+
+    #[tokio::test]
+    async fn test_button_hold_time_grows_while_pressed() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        let context = test_context(&pool);
+
+        let mut press = JoystickState::new();
+        press.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, press);
+        sleep(Duration::from_millis(30)).await;
+
+        let held = pool.fetch_last()["Stick"].button_hold_ms[&288];
+        assert!(held >= 30, "hold time was {} ms", held);
+
+        let mut release = JoystickState::new();
+        release.buttons.insert(288, 0);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, release);
+        assert_eq!(pool.fetch_last()["Stick"].button_hold_ms[&288], 0);
+    }
+
+    #[test]
+    fn test_pressed_edge_is_reported_once_per_press() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        *pool.running.lock().unwrap() = true;
+        let context = test_context(&pool);
+        let press = || {
+            let mut state = JoystickState::new();
+            state.buttons.insert(288, 1);
+            state
+        };
+
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, press());
+        let first = pool.fetch_nowait().unwrap();
+        assert_eq!(first["Stick"].pressed_this_cycle.get(&288), Some(&true));
+
+        // Still held, no new edge
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, press());
+        let second = pool.fetch_nowait().unwrap();
+        assert_eq!(second["Stick"].buttons.get(&288), Some(&1));
+        assert_eq!(second["Stick"].pressed_this_cycle.get(&288), Some(&false));
+    }
+
+    #[test]
+    fn test_apply_state_ignores_empty_state() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        let context = test_context(&pool);
+
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, JoystickState::new());
+
+        assert!(!pool.device_report()[0].receiving_data);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_all_devices_without_descriptions() {
+        let pool = DevicePool::from_descriptions(Vec::new(), 0.1, false);
+        let result = pool
+            .wait_for_all_devices(Some(Duration::from_millis(10)))
+            .await;
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_all_devices_times_out_listing_missing() {
+        let pool = DevicePool::from_descriptions(
+            vec![test_description("Stick"), test_description("Throttle")],
+            0.1,
+            false,
+        );
+
+        let result = pool
+            .wait_for_all_devices(Some(Duration::from_millis(50)))
+            .await;
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("Stick"));
+        match error {
+            DevicePoolError::MissingDevices(missing) => {
+                assert_eq!(missing, vec!["Stick".to_string(), "Throttle".to_string()])
+            }
+            error => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_monitor_panic_is_reported() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        let context = test_context(&pool);
+        context.update_status("Stick", |status| status.monitored = true);
+
+        let input_register = Arc::clone(&pool.input_register);
+        let handle = DevicePool::spawn_supervised(context, "Stick".to_string(), async move {
+            let _guard = input_register.lock().unwrap();
+            panic!("device exploded");
+        });
+        handle.await.unwrap();
+
+        let report = pool.device_report();
+        assert!(!report[0].monitored);
+        assert_eq!(
+            report[0].last_error.as_deref(),
+            Some("Monitor task panicked: device exploded")
+        );
+        assert_eq!(
+            pool.last_errors().get("Stick").map(String::as_str),
+            Some("Monitor task panicked: device exploded")
+        );
+        // The register poisoned by the panic stays usable
+        assert!(pool.input_register.lock().is_ok());
+    }
+
+    fn joystick_info(path: &str, name: &str) -> JoystickInfo {
+        JoystickInfo {
+            path: path.to_string(),
+            name: name.to_string(),
+            ..JoystickInfo::default()
+        }
+    }
+
+    #[test]
+    fn test_hotplug_changes_detects_appeared_and_removed_devices() {
+        let descriptions = [test_description("Stick"), test_description("Throttle")];
+        let monitored: HashMap<String, String> = [
+            ("/dev/input/event3", "Stick"),
+            ("/dev/input/event4", "Throttle"),
+        ]
+        .into_iter()
+        .map(|(path, name)| (path.to_string(), name.to_string()))
+        .collect();
+        let connected = vec![
+            joystick_info("/dev/input/event3", "Stick"),
+            joystick_info("/dev/input/event7", "Throttle"),
+            joystick_info("/dev/input/event8", "Keyboard"),
+        ];
+
+        let (appeared, disappeared) =
+            DevicePool::hotplug_changes(&monitored, &connected, &descriptions);
+
+        assert_eq!(appeared.len(), 1);
+        assert_eq!(appeared[0].path, "/dev/input/event7");
+        assert_eq!(appeared[0].name, "Throttle");
+        assert_eq!(
+            disappeared,
+            vec![("/dev/input/event4".to_string(), "Throttle".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_described_devices_skips_undescribed() {
+        let descriptions = [test_description("Stick")];
+        let connected = vec![
+            joystick_info("/dev/input/event3", "Stick"),
+            joystick_info("/dev/input/event5", "Keyboard"),
+        ];
+
+        let devices = DevicePool::described_devices(connected, &descriptions);
+
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].0.path, "/dev/input/event3");
+        assert_eq!(devices[0].1.device_name, "Stick");
+    }
+
+    #[test]
+    fn test_hotplug_changes_without_changes() {
+        let descriptions = [test_description("Stick")];
+        let monitored: HashMap<String, String> =
+            [("/dev/input/event3".to_string(), "Stick".to_string())].into();
+        let connected = vec![joystick_info("/dev/input/event3", "Stick")];
+
+        let (appeared, disappeared) =
+            DevicePool::hotplug_changes(&monitored, &connected, &descriptions);
+
+        assert!(appeared.is_empty());
+        assert!(disappeared.is_empty());
+    }
+
+    /// Reports an empty state every millisecond, counting the reads.
+    struct CountingSource(Arc<AtomicUsize>);
+
+    impl StateSource for CountingSource {
+        async fn next_state(&mut self) -> std::io::Result<JoystickState> {
+            sleep(Duration::from_millis(1)).await;
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(JoystickState::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drop_stops_monitor_loops() {
+        let mut pool = DevicePool::new(Vec::new(), 0.1);
+        pool.start_monitoring().await;
+        let context = MonitorContext {
+            shutdown: pool.shutdown_tx.as_ref().unwrap().subscribe(),
+            ..test_context(&pool)
+        };
+        let live_monitors = Arc::clone(&pool.live_monitors);
+
+        // A monitor wired up like the ones the supervisor spawns
+        let reads = Arc::new(AtomicUsize::new(0));
+        let mut source = CountingSource(Arc::clone(&reads));
+        let description = test_description("Stick");
+        let monitor =
+            DevicePool::spawn_supervised(context.clone(), "Stick".to_string(), async move {
+                DevicePool::read_states(&mut source, &description, &context).await
+            });
+
+        sleep(Duration::from_millis(20)).await;
+        drop(pool);
+
+        tokio::time::timeout(Duration::from_secs(1), monitor)
+            .await
+            .expect("monitor kept reading after the pool was dropped")
+            .unwrap();
+        let stopped_at = reads.load(Ordering::SeqCst);
+        assert!(stopped_at > 0);
+        assert_eq!(live_monitors.lock().unwrap().get("Stick"), Some(&0));
+
+        sleep(Duration::from_millis(20)).await;
+        assert_eq!(reads.load(Ordering::SeqCst), stopped_at);
+    }
+
+    #[tokio::test]
+    async fn test_monitor_clean_exit_records_no_error() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        let context = test_context(&pool);
+
+        let handle = DevicePool::spawn_supervised(context, "Stick".to_string(), async {});
+        handle.await.unwrap();
+
+        assert!(pool.last_errors().is_empty());
+    }
+
+    #[test]
+    fn test_debounced_codes_within_window() {
+        let pool = DevicePool::new(Vec::new(), 10.0);
+        {
+            let mut last_times = pool.last_button_time.lock().unwrap();
+            last_times.insert(("Stick".to_string(), 288), Instant::now());
+            last_times.insert(("Throttle".to_string(), 288), Instant::now());
+            last_times.insert(
+                ("Stick".to_string(), 289),
+                Instant::now() - Duration::from_secs(20),
+            );
+            last_times.insert(("Stick".to_string(), 16), Instant::now());
+        }
+
+        assert_eq!(
+            pool.debounced_codes(),
+            vec![
+                ("Stick".to_string(), 16),
+                ("Stick".to_string(), 288),
+                ("Throttle".to_string(), 288),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_debounced_codes_empty() {
+        let pool = DevicePool::new(Vec::new(), 0.1);
+        assert!(pool.debounced_codes().is_empty());
+
+        assert!(DevicePool::should_update_input(
+            "Stick",
+            304,
+            false,
+            &pool.last_button_time,
+            pool.debounce_time
+        ));
+        assert_eq!(pool.debounced_codes(), vec![("Stick".to_string(), 304)]);
+    }
+
+    #[test]
+    fn test_debounce_is_tracked_per_device() {
+        let pool = DevicePool::new(Vec::new(), 10.0);
+
+        assert!(DevicePool::should_update_input(
+            "Stick A",
+            304,
+            false,
+            &pool.last_button_time,
+            pool.debounce_time
+        ));
+        assert!(DevicePool::should_update_input(
+            "Stick B",
+            304,
+            false,
+            &pool.last_button_time,
+            pool.debounce_time
+        ));
+        assert!(!DevicePool::should_update_input(
+            "Stick A",
+            304,
+            false,
+            &pool.last_button_time,
+            pool.debounce_time
+        ));
+    }
+
+    /// Replays a fixed list of read results, then fails like an unplugged device.
+    struct ScriptedSource(std::collections::VecDeque<std::io::Result<JoystickState>>);
+
+    impl StateSource for ScriptedSource {
+        async fn next_state(&mut self) -> std::io::Result<JoystickState> {
+            self.0
+                .pop_front()
+                .unwrap_or_else(|| Err(std::io::Error::from_raw_os_error(19)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_error_zeroes_device_and_ends_monitor() {
+        let description = test_description("Stick");
+        let pool = DevicePool::from_descriptions(vec![description.clone()], 0.0, false);
+        let (_shutdown_tx, shutdown) = watch::channel(false);
+        let context = MonitorContext {
+            shutdown,
+            ..test_context(&pool)
+        };
+
+        let mut state = JoystickState::new();
+        state.buttons.insert(288, 1);
+        state.axes.insert(0, 0.5);
+        let mut source = ScriptedSource([Ok(state)].into());
+
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            DevicePool::read_states(&mut source, &description, &context),
+        )
+        .await
+        .expect("monitor loop should end on a read error");
+
+        assert_eq!(
+            pool.input_register.lock().unwrap()["Stick"],
+            description.build_state()
+        );
+        assert!(pool.button_press_time.lock().unwrap().is_empty());
+        assert_eq!(pool.last_errors().len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_monitors_and_fetches_do_not_deadlock() {
+        let names = ["Stick", "Throttle", "Pedals", "Panel"];
+        let pool = Arc::new(DevicePool::from_descriptions(
+            names.iter().map(|name| test_description(name)).collect(),
+            0.0,
+            false,
+        ));
+        *pool.running.lock().unwrap() = true;
+
+        let mut tasks = Vec::new();
+        for name in names {
+            let context = test_context(&pool);
+            tasks.push(tokio::spawn(async move {
+                for i in 0..500 {
+                    let mut state = JoystickState::new();
+                    state.buttons.insert(288, (i % 2) as u8);
+                    state.axes.insert(0, (i % 10) as f32 / 10.0);
+                    DevicePool::apply_state(&context, name, AxisRotation::Deg0, state);
+                    tokio::task::yield_now().await;
+                }
+            }));
+        }
+        for _ in 0..2 {
+            let pool = Arc::clone(&pool);
+            tasks.push(tokio::spawn(async move {
+                for _ in 0..200 {
+                    let _ = pool.fetch(Some(Duration::from_millis(1)), None).await;
+                    let _ = pool.fetch_nowait();
+                }
+            }));
+        }
+
+        tokio::time::timeout(Duration::from_secs(10), futures::future::join_all(tasks))
+            .await
+            .expect("monitors and fetches should not deadlock")
+            .into_iter()
+            .for_each(|result| result.unwrap());
+    }
+
+    #[test]
+    fn test_merged_description_aggregates_members() {
+        let mut stick = test_description("Stick");
+        stick.buttons = vec![crate::inner::description::DeviceItem::new(288, None)];
+        let mut throttle = test_description("Throttle");
+        throttle.axes = vec![crate::inner::description::DeviceItem::new(2, None)];
+        throttle.buttons = vec![crate::inner::description::DeviceItem::new(289, None)];
+        let hotas = stick.merge_rust(&throttle).unwrap();
+
+        let paired = DevicePool::described_devices(
+            vec![
+                joystick_info("/dev/input/event3", "Throttle"),
+                joystick_info("/dev/input/event4", "Mouse"),
+                joystick_info("/dev/input/event5", "Stick"),
+            ],
+            std::slice::from_ref(&hotas),
+        );
+        let paired: Vec<(&str, &str)> = paired
+            .iter()
+            .map(|(info, desc)| (info.name.as_str(), desc.device_name.as_str()))
+            .collect();
+        assert_eq!(paired, vec![("Throttle", "Stick"), ("Stick", "Stick")]);
+
+        let pool = DevicePool::from_descriptions(vec![hotas], 0.0, false);
+        let context = test_context(&pool);
+        let mut from_stick = JoystickState::new();
+        from_stick.buttons.insert(288, 1);
+        let mut from_throttle = JoystickState::new();
+        from_throttle.axes.insert(2, 0.5);
+        from_throttle.buttons.insert(289, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, from_stick);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, from_throttle);
+
+        let state = &pool.fetch_last()["Stick"];
+        assert_eq!(state.buttons[&288], 1);
+        assert_eq!(state.buttons[&289], 1);
+        assert_eq!(state.axes[&2], 0.5);
+        assert_eq!(pool.fetch_last().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reset_preserve_axes_keeps_axes_and_clears_buttons() {
+        let mut pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        let context = test_context(&pool);
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.75);
+        state.raw_axes.insert(0, 896);
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+
+        pool.reset_preserve_axes().await;
+        let after = &pool.fetch_last()["Stick"];
+        assert_eq!(after.axes[&0], 0.75);
+        assert_eq!(after.raw_axes[&0], 896);
+        assert_eq!(after.buttons[&288], 0);
+
+        pool.reset().await;
+        assert_eq!(pool.fetch_last()["Stick"].axes[&0], 0.0);
+        pool.stop(None).await;
+    }
+
+    #[test]
+    fn test_per_button_debounce_overrides_pool_default() {
+        let description = DeviceDescription::from_toml_str_rust(
+            r#"
+            device_name = "Stick"
+            buttons = [{ code = 288, debounce_ms = 0 }, { code = 289, debounce_ms = 60000 }]
+            "#,
+        )
+        .unwrap();
+        let pool = DevicePool::from_descriptions(vec![description], 10.0, false);
+        let context = test_context(&pool);
+        let buttons = |value| {
+            let mut state = JoystickState::new();
+            state.buttons.insert(288, value);
+            state.buttons.insert(289, value);
+            state
+        };
+
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, buttons(1));
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, buttons(0));
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, buttons(1));
+
+        let state = &pool.fetch_last()["Stick"];
+        // The trigger follows every change, the second press of the toggle is debounced
+        assert_eq!(state.buttons[&288], 1);
+        assert_eq!(state.buttons[&289], 0);
+        assert_eq!(pool.debounced_codes(), vec![("Stick".to_string(), 289)]);
+    }
+
+    #[test]
+    fn test_apply_state_timestamps_without_affecting_equality() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        let context = test_context(&pool);
+        let axis = || {
+            let mut state = JoystickState::new();
+            state.axes.insert(0, 0.5);
+            state
+        };
+
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, axis());
+        let first = pool.fetch_last()["Stick"].clone();
+        std::thread::sleep(Duration::from_millis(2));
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, axis());
+        let second = pool.fetch_last()["Stick"].clone();
+
+        assert!(first.timestamp_us > 0);
+        assert!(second.timestamp_us > first.timestamp_us);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_fetch_nowait_changed_only_reports_changes() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        let context = test_context(&pool);
+        assert!(matches!(
+            pool.fetch_nowait_changed(),
+            Err(DevicePoolError::NotRunning)
+        ));
+        *pool.running.lock().unwrap() = true;
+
+        pool.fetch_nowait().unwrap();
+        assert!(matches!(pool.fetch_nowait_changed(), Ok(None)));
+
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.5);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+        let changed = pool.fetch_nowait_changed().unwrap().unwrap();
+        assert_eq!(changed["Stick"].axes[&0], 0.5);
+
+        assert!(matches!(pool.fetch_nowait_changed(), Ok(None)));
+    }
+
+    #[test]
+    fn test_device_pool_error_messages_and_source() {
+        use std::error::Error;
+
+        assert_eq!(
+            DevicePoolError::Timeout.to_string(),
+            "Fetch operation timed out"
+        );
+        assert!(DevicePoolError::NotRunning.source().is_none());
+
+        let error = DevicePoolError::from(std::io::Error::from_raw_os_error(19));
+        assert!(matches!(error, DevicePoolError::Io(_)));
+        assert!(error.source().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_axis_smoothing_converges_after_single_step() {
+        let description = DeviceDescription::from_toml_str_rust(
+            r#"
+            device_name = "Stick"
+            axes = [{ code = 0, smoothing = 0.5 }, { code = 1 }]
+            "#,
+        )
+        .unwrap();
+        let pool = DevicePool::from_descriptions(vec![description.clone()], 0.0, false);
+        let (_shutdown_tx, shutdown) = watch::channel(false);
+        let context = MonitorContext {
+            shutdown,
+            ..test_context(&pool)
+        };
+        // The device reports the step once and then holds the position
+        let mut step = JoystickState::new();
+        step.axes.insert(0, 1.0);
+        step.axes.insert(1, 1.0);
+        let monitor = tokio::spawn(async move {
+            let mut source = IdleAfterSource(vec![step].into());
+            DevicePool::read_states(&mut source, &description, &context).await
+        });
+
+        sleep(Duration::from_millis(5)).await;
+        let state = &pool.fetch_last()["Stick"];
+        assert_eq!(state.axes[&1], 1.0);
+        assert!(state.axes[&0] < 1.0);
+
+        tokio::time::timeout(Duration::from_secs(2), async {
+            while pool.fetch_last()["Stick"].axes[&0] != 1.0 {
+                sleep(SMOOTHING_TICK).await;
+            }
+        })
+        .await
+        .expect("smoothed axis never reached the held position");
+        assert!(pool.smoothing_targets.lock().unwrap().is_empty());
+        monitor.abort();
+    }
+
+    #[test]
+    fn test_smooth_step_blends_and_settles() {
+        assert_eq!(smooth_step(0.5, 0.0, 1.0), 0.5);
+        assert_eq!(smooth_step(0.5, 0.5, 1.0), 0.75);
+        assert_eq!(smooth_step(0.5, 1.0 - SMOOTHING_SETTLED, 1.0), 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_held_button_survives_fetches() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        let context = test_context(&pool);
+        *pool.running.lock().unwrap() = true;
+
+        let mut press = JoystickState::new();
+        press.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, press);
+
+        let first = pool.fetch_nowait().unwrap();
+        assert_eq!(first["Stick"].buttons[&288], 1);
+        assert_eq!(first["Stick"].held[&288], 1);
+        for _ in 0..3 {
+            let later = pool.fetch_nowait().unwrap();
+            assert_eq!(later["Stick"].buttons[&288], 0);
+            assert_eq!(later["Stick"].held[&288], 1);
+        }
+
+        let mut release = JoystickState::new();
+        release.buttons.insert(288, 0);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, release);
+        let released = pool
+            .fetch_nowait_changed()
+            .unwrap()
+            .expect("a release changes the held level");
+        assert_eq!(released["Stick"].held[&288], 0);
+    }
+
+    #[test]
+    fn test_same_named_devices_distinguished_by_phys() {
+        let described = |name: &str, phys: &str| {
+            let mut desc = test_description(name);
+            desc.phys = Some(phys.to_string());
+            desc
+        };
+        let descriptions = [
+            test_description("T.16000M"),
+            described("Left Stick", "usb-0000:00:14.0-1/input0"),
+            described("Right Stick", "usb-0000:00:14.0-2/input0"),
+        ];
+        let device = |path: &str, phys: &str| JoystickInfo {
+            phys: Some(phys.to_string()),
+            ..joystick_info(path, "T.16000M")
+        };
+        let connected = vec![
+            device("/dev/input/event3", "usb-0000:00:14.0-2/input0"),
+            device("/dev/input/event4", "usb-0000:00:14.0-1/input0"),
+        ];
+
+        let paired: Vec<(String, String)> =
+            DevicePool::described_devices(connected.clone(), &descriptions)
+                .into_iter()
+                .map(|(info, desc)| (info.path, desc.device_name))
+                .collect();
+        assert_eq!(
+            paired,
+            vec![
+                ("/dev/input/event3".to_string(), "Right Stick".to_string()),
+                ("/dev/input/event4".to_string(), "Left Stick".to_string()),
+            ]
+        );
+        assert_eq!(
+            DevicePool::connected_descriptions(&descriptions, &connected[..1]),
+            vec!["T.16000M".to_string(), "Right Stick".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_description_binds_by_usb_ids_despite_name() {
+        let mut by_ids = test_description("My Stick");
+        by_ids.vendor_id = Some(0x044f);
+        by_ids.product_id = Some(0xb10a);
+        let descriptions = [test_description("Thrustmaster T.16000M"), by_ids];
+        let device = |path: &str, name: &str, product_id| JoystickInfo {
+            vendor_id: 0x044f,
+            product_id,
+            ..joystick_info(path, name)
+        };
+        let connected = vec![
+            device("/dev/input/event3", "Thrustmaster T.16000M", 0xb10a),
+            device("/dev/input/event4", "Thrustmaster TWCS", 0xb687),
+        ];
+
+        let paired: Vec<(String, String)> =
+            DevicePool::described_devices(connected.clone(), &descriptions)
+                .into_iter()
+                .map(|(info, desc)| (info.path, desc.device_name))
+                .collect();
+        assert_eq!(
+            paired,
+            vec![("/dev/input/event3".to_string(), "My Stick".to_string())]
+        );
+        assert_eq!(
+            DevicePool::connected_descriptions(&descriptions, &connected),
+            vec!["Thrustmaster T.16000M".to_string(), "My Stick".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tap_within_one_batch_registers_press() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        let context = test_context(&pool);
+        *pool.running.lock().unwrap() = true;
+        pool.fetch_nowait().unwrap();
+
+        let mut tap = JoystickState::new();
+        tap.buttons.insert(288, 0);
+        tap.pressed_this_cycle.insert(288, true);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, tap);
+
+        let states = pool
+            .fetch_nowait_changed()
+            .unwrap()
+            .expect("a tap is a change");
+        assert_eq!(states["Stick"].buttons[&288], 1);
+        assert!(states["Stick"].pressed_this_cycle[&288]);
+        assert_eq!(states["Stick"].held[&288], 0);
+        assert_eq!(pool.fetch_nowait().unwrap()["Stick"].buttons[&288], 0);
+    }
+
+    #[tokio::test]
+    async fn test_axis_threshold_synthesizes_button() {
+        let description = DeviceDescription::from_toml_str_rust(
+            r#"
+            device_name = "Pad"
+            axes = [{ code = 2, button_code = 312 }]
+            "#,
+        )
+        .unwrap();
+        let pool = DevicePool::from_descriptions(vec![description], 0.0, false);
+        let context = test_context(&pool);
+        let trigger = |value| {
+            let mut state = JoystickState::new();
+            state.axes.insert(2, value);
+            DevicePool::apply_state(&context, "Pad", AxisRotation::Deg0, state);
+            pool.fetch_last()["Pad"].clone()
+        };
+
+        assert_eq!(trigger(0.4).held[&312], 0);
+        let pressed = trigger(0.6);
+        assert_eq!(pressed.buttons[&312], 1);
+        assert_eq!(pressed.held[&312], 1);
+        assert!(pressed.pressed_this_cycle[&312]);
+        assert_eq!(trigger(0.8).held[&312], 1);
+        let released = trigger(0.3);
+        assert_eq!(released.buttons[&312], 0);
+        assert_eq!(released.held[&312], 0);
+    }
+
+    /// Keeps every log record so tests can assert on them.
+    struct CapturingLogger(Mutex<Vec<(log::Level, String)>>);
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            let message = record.args().to_string();
+            self.0.lock().unwrap().push((record.level(), message));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CapturingLogger = CapturingLogger(Mutex::new(Vec::new()));
+
+    fn captured_logs() -> &'static CapturingLogger {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            log::set_logger(&LOGGER).unwrap();
+            log::set_max_level(log::LevelFilter::Trace);
+        });
+        &LOGGER
+    }
+
+    #[tokio::test]
+    async fn test_monitor_logs_start_at_info_level() {
+        let logs = captured_logs();
+        let description = test_description("Logged Stick");
+        let pool = DevicePool::from_descriptions(vec![description.clone()], 0.0, false);
+        let (_shutdown_tx, shutdown) = watch::channel(false);
+        let context = MonitorContext {
+            shutdown,
+            ..test_context(&pool)
+        };
+
+        // The empty source fails its first read, which ends the monitor
+        let mut source = ScriptedSource(std::collections::VecDeque::new());
+        DevicePool::run_monitor(&mut source, &description, &context).await;
+
+        let records = logs.0.lock().unwrap().clone();
+        let level_of = |message: &str| {
+            records
+                .iter()
+                .find(|(_, logged)| logged == message)
+                .map(|(level, _)| *level)
+        };
+        assert_eq!(
+            level_of("Started monitoring Logged Stick"),
+            Some(log::Level::Info)
+        );
+        assert_eq!(
+            level_of("Stopped monitoring Logged Stick"),
+            Some(log::Level::Info)
+        );
+        assert!(records
+            .iter()
+            .any(|(level, logged)| *level == log::Level::Warn
+                && logged.starts_with("Failed to read from Logged Stick")));
+    }
+
+    #[test]
+    fn test_axis_crossing_records_direction_until_fetch() {
+        let mut description = test_description("Stick");
+        description.axes[0].track_crossings = true;
+        let pool = DevicePool::from_descriptions(vec![description], 0.0, false);
+        let context = test_context(&pool);
+        *pool.running.lock().unwrap() = true;
+        let axis = |value| {
+            let mut state = JoystickState::new();
+            state.axes.insert(0, value);
+            state
+        };
+
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, axis(-0.5));
+        assert_eq!(pool.fetch_last()["Stick"].axis_crossings.get(&0), Some(&0));
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, axis(0.5));
+
+        let fetched = pool.fetch_nowait().unwrap();
+        assert_eq!(fetched["Stick"].axis_crossings.get(&0), Some(&1));
+        // The crossing is a trigger, cleared by the fetch
+        assert_eq!(pool.fetch_last()["Stick"].axis_crossings.get(&0), Some(&0));
+    }
+
+    #[test]
+    fn test_pool_runs_on_user_runtime() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+        let mut pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        pool.set_runtime(runtime.handle().clone());
+
+        // Driven without any tokio context, the supervisor goes to the given runtime
+        futures::executor::block_on(pool.start());
+        assert!(pool.is_running());
+        assert!(runtime.metrics().num_alive_tasks() >= 1);
+        assert!(pool.fetch_nowait().unwrap().contains_key("Stick"));
+
+        futures::executor::block_on(pool.stop(None));
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while runtime.metrics().num_alive_tasks() > 0 && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(runtime.metrics().num_alive_tasks(), 0);
+    }
+
+    #[test]
+    fn test_fetch_blocking_outside_async_context() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        *pool.running.lock().unwrap() = true;
+
+        let states = pool
+            .fetch_blocking(Some(Duration::from_millis(20)))
+            .unwrap();
+        assert!(states.contains_key("Stick"));
+        // Nothing changed since, so the next fetch runs into its timeout
+        assert!(matches!(
+            pool.fetch_blocking(Some(Duration::from_millis(20))),
+            Err(DevicePoolError::Timeout)
+        ));
+    }
+
+    /// Replays a fixed list of states, then waits forever like an idle device.
+    struct IdleAfterSource(std::collections::VecDeque<JoystickState>);
+
+    impl StateSource for IdleAfterSource {
+        async fn next_state(&mut self) -> std::io::Result<JoystickState> {
+            match self.0.pop_front() {
+                Some(state) => Ok(state),
+                None => std::future::pending().await,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ignored_codes_do_not_alter_state() {
+        let mut description = test_description("Stick");
+        description.ignore = vec![40];
+        let pool = DevicePool::from_descriptions(vec![description.clone()], 0.0, false);
+        let (_shutdown_tx, shutdown) = watch::channel(false);
+        let context = MonitorContext {
+            shutdown,
+            ..test_context(&pool)
+        };
+
+        let mut phantom = JoystickState::new();
+        phantom.axes.insert(40, 0.7);
+        phantom.raw_axes.insert(40, 700);
+        let mut mixed = phantom.clone();
+        mixed.axes.insert(0, 0.5);
+        let mut source = IdleAfterSource([phantom.clone(), mixed, phantom].into());
+        let version = pool.register_version.load(Ordering::SeqCst);
+
+        let _ = tokio::time::timeout(
+            Duration::from_millis(100),
+            DevicePool::read_states(&mut source, &description, &context),
+        )
+        .await;
+
+        let register = pool.input_register.lock().unwrap();
+        assert_eq!(register["Stick"].axes, HashMap::from([(0, 0.5)]));
+        assert!(!register["Stick"].raw_axes.contains_key(&40));
+        // Only the state with a real input bumped the version
+        assert_eq!(pool.register_version.load(Ordering::SeqCst), version + 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_opens_are_retried_until_monitoring_starts() {
+        let description = test_description("Stick");
+        let mut pool = DevicePool::from_descriptions(vec![description.clone()], 0.0, false);
+        pool.set_open_retries(3, Duration::from_millis(1));
+        let (_shutdown_tx, shutdown) = watch::channel(false);
+        let context = MonitorContext {
+            shutdown,
+            ..test_context(&pool)
+        };
+
+        let mut attempts = 0;
+        let open = || {
+            attempts += 1;
+            if attempts <= 2 {
+                Err(std::io::Error::from_raw_os_error(16))
+            } else {
+                Ok(IdleAfterSource(std::collections::VecDeque::new()))
+            }
+        };
+        let _ = tokio::time::timeout(
+            Duration::from_millis(200),
+            DevicePool::open_and_monitor(open, &description, &context),
+        )
+        .await;
+
+        assert_eq!(attempts, 3);
+        assert!(pool.device_report()[0].monitored);
+        assert!(pool.last_errors().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_open_retries_give_up_after_the_cap() {
+        let description = test_description("Stick");
+        let mut pool = DevicePool::from_descriptions(vec![description.clone()], 0.0, false);
+        pool.set_open_retries(2, Duration::from_millis(1));
+        let (_shutdown_tx, shutdown) = watch::channel(false);
+        let context = MonitorContext {
+            shutdown,
+            ..test_context(&pool)
+        };
+
+        let mut attempts = 0;
+        let open = || {
+            attempts += 1;
+            Err::<IdleAfterSource, _>(std::io::Error::from_raw_os_error(16))
+        };
+        DevicePool::open_and_monitor(open, &description, &context).await;
+
+        assert_eq!(attempts, 3);
+        assert!(!pool.device_report()[0].monitored);
+        assert_eq!(pool.last_errors().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_reports_changed_device() {
+        let pool = DevicePool::from_descriptions(
+            vec![test_description("Stick"), test_description("Throttle")],
+            0.0,
+            false,
+        );
+        let context = test_context(&pool);
+        let mut activity = pool.subscribe();
+
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.25);
+        DevicePool::apply_state(&context, "Throttle", AxisRotation::Deg0, state);
+        // Empty states are no activity
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, JoystickState::new());
+
+        assert_eq!(activity.recv().await.unwrap(), "Throttle");
+        assert!(matches!(
+            activity.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+    }
+
+    #[test]
+    fn test_concurrent_resets_run_one_monitor_set() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .build()
+            .unwrap();
+        let pool = Arc::new(tokio::sync::Mutex::new(DevicePool::from_descriptions(
+            vec![test_description("Stick")],
+            0.1,
+            false,
+        )));
+
+        runtime.block_on(async {
+            let reset = || {
+                let pool = Arc::clone(&pool);
+                async move { pool.lock().await.reset().await }
+            };
+            tokio::join!(reset(), reset());
+
+            // The first set was shut down and awaited before the second one started
+            assert_eq!(runtime.metrics().num_alive_tasks(), 1);
+            let mut pool = pool.lock().await;
+            assert!(pool.is_running());
+            pool.stop(None).await;
+            pool.stop(None).await;
+            assert!(!pool.is_running());
+            assert_eq!(runtime.metrics().num_alive_tasks(), 0);
+        });
+    }
+
+    #[tokio::test]
+    async fn test_fetch_device_returns_only_that_device() {
+        let pool = DevicePool::from_descriptions(
+            vec![test_description("Stick"), test_description("Throttle")],
+            0.0,
+            false,
+        );
+        *pool.running.lock().unwrap() = true;
+        pool.fetch_nowait().unwrap();
+        let context = test_context(&pool);
+
+        let mut state = JoystickState::new();
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state.clone());
+        DevicePool::apply_state(&context, "Throttle", AxisRotation::Deg0, state);
+
+        let stick = pool
+            .fetch_device("Stick", Some(Duration::from_millis(50)))
+            .await
+            .unwrap();
+        assert_eq!(stick.buttons[&288], 1);
+
+        // The other device's change is still pending
+        let pending = pool.fetch_nowait_changed().unwrap().unwrap();
+        assert_eq!(pending["Throttle"].buttons[&288], 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_device_rejects_unknown_device() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        *pool.running.lock().unwrap() = true;
+
+        let result = pool
+            .fetch_device("Rudder", Some(Duration::from_millis(50)))
+            .await;
+        assert!(matches!(
+            result,
+            Err(DevicePoolError::UnknownDevice(ref name)) if name == "Rudder"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_device_times_out_without_changes() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        *pool.running.lock().unwrap() = true;
+        let context = test_context(&pool);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, JoystickState::new());
+        pool.fetch_nowait().unwrap();
+
+        let result = pool
+            .fetch_device("Stick", Some(Duration::from_millis(50)))
+            .await;
+        assert!(matches!(result, Err(DevicePoolError::Timeout)));
+    }
+
+    #[test]
+    fn test_release_inside_debounce_window_goes_through() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 10.0, false);
+        let context = test_context(&pool);
+
+        for value in [1, 0] {
+            let mut state = JoystickState::new();
+            state.buttons.insert(288, value);
+            DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+        }
+        assert_eq!(pool.fetch_last()["Stick"].buttons[&288], 0);
+
+        // A bouncing press right after the release is still debounced
+        let mut state = JoystickState::new();
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+        assert_eq!(pool.fetch_last()["Stick"].buttons[&288], 0);
+    }
+
+    #[test]
+    fn test_descriptions_match_device_names_by_match_mode() {
+        let with_mode = |name: &str, match_mode| DeviceDescription {
+            match_mode,
+            ..test_description(name)
+        };
+        let descriptions = [
+            with_mode("Stick", NameMatch::Exact),
+            test_description("Throttle"),
+            with_mode("rudder pedals", NameMatch::CaseInsensitive),
+            with_mode("TWCS", NameMatch::Substring),
+        ];
+        let connected = vec![
+            joystick_info("/dev/input/event3", "Stick "),
+            joystick_info("/dev/input/event4", "Throttle  "),
+            joystick_info("/dev/input/event5", "Rudder Pedals"),
+            joystick_info("/dev/input/event6", "Thrustmaster TWCS Throttle"),
+        ];
+
+        let paired: Vec<(String, String)> =
+            DevicePool::described_devices(connected.clone(), &descriptions)
+                .into_iter()
+                .map(|(info, desc)| (info.path, desc.device_name))
+                .collect();
+        assert_eq!(
+            paired,
+            vec![
+                ("/dev/input/event4".to_string(), "Throttle".to_string()),
+                ("/dev/input/event5".to_string(), "rudder pedals".to_string()),
+                ("/dev/input/event6".to_string(), "TWCS".to_string()),
+            ]
+        );
+        assert_eq!(
+            DevicePool::connected_descriptions(&descriptions, &connected),
+            vec![
+                "Throttle".to_string(),
+                "rudder pedals".to_string(),
+                "TWCS".to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_events_are_attributed_to_their_device() {
+        let pool = DevicePool::from_descriptions(
+            vec![test_description("Stick"), test_description("Throttle")],
+            0.0,
+            false,
+        );
+        *pool.running.lock().unwrap() = true;
+        let context = test_context(&pool);
+
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.5);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+        let mut state = JoystickState::new();
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Throttle", AxisRotation::Deg0, state);
+        // A tap within one batch logs its press and its release
+        let mut tap = JoystickState::new();
+        tap.buttons.insert(288, 0);
+        tap.pressed_this_cycle.insert(288, true);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, tap);
+
+        let events = pool.events(Some(Duration::from_millis(50))).await;
+        assert_eq!(
+            events,
+            vec![
+                InputEvent::new("Stick", InputKind::Axis, 0, 0.5),
+                InputEvent::new("Throttle", InputKind::Button, 288, 1.0),
+                InputEvent::new("Stick", InputKind::Button, 288, 1.0),
+                InputEvent::new("Stick", InputKind::Button, 288, 0.0),
+            ]
+        );
+
+        // The events were drained, fetches are unaffected
+        assert!(pool
+            .events(Some(Duration::from_millis(50)))
+            .await
+            .is_empty());
+        assert_eq!(pool.fetch_nowait().unwrap()["Throttle"].buttons[&288], 1);
+    }
+
+    /// Hands out the states sent through a channel, then waits forever.
+    struct ChannelSource(mpsc::UnboundedReceiver<JoystickState>);
+
+    impl StateSource for ChannelSource {
+        async fn next_state(&mut self) -> std::io::Result<JoystickState> {
+            match self.0.recv().await {
+                Some(state) => Ok(state),
+                None => std::future::pending().await,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pause_skips_register_writes_until_resumed() {
+        let description = test_description("Stick");
+        let pool = DevicePool::from_descriptions(vec![description.clone()], 0.0, false);
+        let (_shutdown_tx, shutdown) = watch::channel(false);
+        let context = MonitorContext {
+            shutdown,
+            ..test_context(&pool)
+        };
+        let (state_tx, state_rx) = mpsc::unbounded_channel();
+        let monitor = tokio::spawn(async move {
+            DevicePool::read_states(&mut ChannelSource(state_rx), &description, &context).await
+        });
+        let axis = |value| {
+            let mut state = JoystickState::new();
+            state.axes.insert(0, value);
+            state
+        };
+
+        pool.pause();
+        assert!(pool.is_paused());
+        state_tx.send(axis(0.5)).unwrap();
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(pool.fetch_last()["Stick"].axes[&0], 0.0);
+
+        pool.resume();
+        state_tx.send(axis(0.25)).unwrap();
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(pool.fetch_last()["Stick"].axes[&0], 0.25);
+
+        // A button released while paused is not left held
+        let button = |value| {
+            let mut state = JoystickState::new();
+            state.buttons.insert(288, value);
+            state
+        };
+        state_tx.send(button(1)).unwrap();
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(pool.fetch_last()["Stick"].held[&288], 1);
+        pool.pause();
+        state_tx.send(button(0)).unwrap();
+        sleep(Duration::from_millis(50)).await;
+        pool.resume();
+        let state = &pool.fetch_last()["Stick"];
+        assert_eq!(state.held[&288], 0);
+        assert_eq!(state.button_hold_ms[&288], 0);
+        // The same monitor handled every state
+        assert!(!monitor.is_finished());
+        monitor.abort();
+    }
+
+    #[test]
+    fn test_validate_reports_described_but_absent_button() {
+        let mut stick = test_description("Stick");
+        stick.buttons = vec![DeviceItem::new(288, None), DeviceItem::new(300, None)];
+        let descriptions = vec![stick, test_description("Throttle")];
+        let connected = vec![
+            joystick_info("/dev/input/event3", "Stick"),
+            joystick_info("/dev/input/event4", "Unknown"),
+        ];
+
+        let mut opened = Vec::new();
+        let mismatches = DevicePool::validate_with(&descriptions, connected, |path| {
+            opened.push(path.to_string());
+            Ok(HashMap::from([
+                ("axes".to_string(), vec![0]),
+                ("buttons".to_string(), vec![288]),
+            ]))
+        });
+
+        assert_eq!(opened, vec!["/dev/input/event3".to_string()]);
+        // The disconnected throttle cannot be validated and is left out
+        assert_eq!(
+            mismatches,
+            HashMap::from([("Stick".to_string(), vec![300])])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_change_epsilon_ignores_axis_jitter() {
+        let mut pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.0, false);
+        pool.set_change_epsilon(0.01);
+        *pool.running.lock().unwrap() = true;
+        pool.fetch_nowait().unwrap();
+        let context = test_context(&pool);
+        let axis = |value| {
+            let mut state = JoystickState::new();
+            state.axes.insert(0, value);
+            state
+        };
+
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, axis(0.005));
+        let result = pool.fetch(Some(Duration::from_millis(50)), None).await;
+        assert!(matches!(result, Err(DevicePoolError::Timeout)));
+
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, axis(0.05));
+        let states = pool
+            .fetch(Some(Duration::from_millis(50)), None)
+            .await
+            .unwrap();
+        assert_eq!(states["Stick"].axes[&0], 0.05);
+
+        // The same holds without waiting
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, axis(0.055));
+        assert!(pool.fetch_nowait_changed().unwrap().is_none());
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, axis(0.1));
+        let states = pool.fetch_nowait_changed().unwrap().unwrap();
+        assert_eq!(states["Stick"].axes[&0], 0.1);
+
+        // Buttons are compared exactly
+        let mut state = JoystickState::new();
+        state.buttons.insert(288, 1);
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, state);
+        let states = pool
+            .fetch(Some(Duration::from_millis(50)), None)
+            .await
+            .unwrap();
+        assert_eq!(states["Stick"].buttons[&288], 1);
+    }
+
+    #[tokio::test]
+    async fn test_device_health_flips_when_monitor_exits() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        let context = test_context(&pool);
+        assert!(pool.device_health().is_empty());
+
+        let (exit_tx, exit_rx) = tokio::sync::oneshot::channel::<()>();
+        let handle = DevicePool::spawn_supervised(context.clone(), "Stick".to_string(), async {
+            let _ = exit_rx.await;
+        });
+        assert_eq!(
+            pool.device_health(),
+            HashMap::from([("Stick".to_string(), true)])
+        );
+
+        exit_tx.send(()).unwrap();
+        handle.await.unwrap();
+        assert_eq!(
+            pool.device_health(),
+            HashMap::from([("Stick".to_string(), false)])
+        );
+
+        // A panicking monitor is reported as exited too
+        let handle = DevicePool::spawn_supervised(context, "Stick".to_string(), async {
+            panic!("device exploded");
+        });
+        handle.await.unwrap();
+        assert_eq!(
+            pool.device_health(),
+            HashMap::from([("Stick".to_string(), false)])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stop_with_drain_timeout_cancels_stubborn_monitors() {
+        let mut pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        let (shutdown_tx, shutdown) = watch::channel(false);
+        let (cancel_tx, cancel) = watch::channel(false);
+        let context = MonitorContext {
+            shutdown,
+            cancel,
+            ..test_context(&pool)
+        };
+
+        // A monitor that ignores the shutdown signal and keeps writing
+        let input_register = Arc::clone(&pool.input_register);
+        let monitor = async move {
+            loop {
+                input_register
+                    .lock()
+                    .unwrap()
+                    .get_mut("Stick")
+                    .unwrap()
+                    .timestamp_us += 1;
+                sleep(Duration::from_millis(1)).await;
+            }
+        };
+        let task = DevicePool::spawn_supervised(context, "Stick".to_string(), monitor);
+        *pool.running.lock().unwrap() = true;
+        pool.shutdown_tx = Some(shutdown_tx);
+        pool.cancel_tx = Some(cancel_tx);
+        pool.supervisor = Some(tokio::spawn(async move {
+            let _ = task.await;
+        }));
+
+        pool.stop(Some(Duration::from_millis(20))).await;
+
+        assert_eq!(
+            pool.device_health(),
+            HashMap::from([("Stick".to_string(), false)])
+        );
+        let written = pool.fetch_last()["Stick"].timestamp_us;
+        assert!(written > 0);
+        sleep(Duration::from_millis(20)).await;
+        assert_eq!(pool.fetch_last()["Stick"].timestamp_us, written);
+    }
+
+    #[test]
+    fn test_axis_crossing_follows_rotation_onto_logical_axis() {
+        let mut description = test_description("Stick");
+        description.rotation = AxisRotation::Deg90;
+        description.axes[0].track_crossings = true;
+        description.axes.push(DeviceItem::new(1, None));
+        let pool = DevicePool::from_descriptions(vec![description], 0.0, false);
+        let context = test_context(&pool);
+        *pool.running.lock().unwrap() = true;
+        let axes = |x, y| {
+            let mut state = JoystickState::new();
+            state.axes.extend([(0, x), (1, y)]);
+            state
+        };
+
+        // Physical X is logical Y on a stick rotated by 90 degrees
+        let seeded = &pool.fetch_last()["Stick"].axis_crossings;
+        assert_eq!(seeded, &HashMap::from([(1, 0)]));
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg90, axes(-0.5, -0.5));
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg90, axes(0.5, 0.5));
+
+        let fetched = pool.fetch_nowait().unwrap();
+        // Logical Y went from 0.5 to -0.5, the untracked physical Y is not recorded
+        assert_eq!(fetched["Stick"].axis_crossings, HashMap::from([(1, -1)]));
+    }
+
+    /// Returns a monitor task that has already exited.
+    async fn finished_task() -> JoinHandle<()> {
+        let task = tokio::spawn(async {});
+        while !task.is_finished() {
+            tokio::task::yield_now().await;
+        }
+        task
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_reports_vanished_device_with_exited_monitor() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 0.1, false);
+        let context = test_context(&pool);
+        let descriptions = [test_description("Stick"), test_description("Throttle")];
+        let mut tasks = HashMap::new();
+        for (path, name) in [
+            ("/dev/input/event3", "Stick"),
+            ("/dev/input/event4", "Throttle"),
+        ] {
+            tasks.insert(
+                path.to_string(),
+                TrackedMonitor {
+                    name: name.to_string(),
+                    task: finished_task().await,
+                    restarts: 0,
+                    retry_at: None,
+                },
+            );
+        }
+        // The monitor of event4 exited on ENODEV, the device is gone
+        let connected = vec![joystick_info("/dev/input/event3", "Stick")];
+        let (hotplug_tx, mut hotplug_rx) = mpsc::unbounded_channel();
+
+        for _ in 0..3 {
+            DevicePool::reconcile_devices(
+                &mut tasks,
+                &connected,
+                &descriptions,
+                &context,
+                Some(&hotplug_tx),
+            );
+        }
+
+        assert_eq!(
+            hotplug_rx.try_recv(),
+            Ok(HotplugEvent::Disconnected("Throttle".to_string()))
+        );
+        // The still connected device is neither reported again nor restarted right away
+        assert!(hotplug_rx.try_recv().is_err());
+        assert_eq!(tasks.len(), 1);
+        let stick = &tasks["/dev/input/event3"];
+        assert!(stick.task.is_finished());
+        assert_eq!(stick.restarts, 0);
+        assert!(stick.retry_at.is_some());
+    }
+
+    #[test]
+    fn test_respawn_delay_doubles_up_to_the_cap() {
+        assert_eq!(respawn_delay(0), HOTPLUG_INTERVAL);
+        assert_eq!(respawn_delay(1), HOTPLUG_INTERVAL * 2);
+        assert_eq!(respawn_delay(3), HOTPLUG_INTERVAL * 8);
+        assert_eq!(respawn_delay(40), MAX_RESPAWN_DELAY);
+    }
+
+    #[test]
+    fn test_debounced_bounce_does_not_fire_callback_again() {
+        let pool = DevicePool::from_descriptions(vec![test_description("Stick")], 10.0, false);
+        let context = test_context(&pool);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        pool.on_button(
+            "Stick",
+            288,
+            Arc::new(move |_: &str, _| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }),
+        );
+        let button = |value| {
+            let mut state = JoystickState::new();
+            state.buttons.insert(288, value);
+            state
+        };
+
+        // A contact bounce within the debounce window
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, button(1));
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, button(0));
+        DevicePool::apply_state(&context, "Stick", AxisRotation::Deg0, button(1));
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_callback_worker_keeps_dispatch_order() {
+        let callback_tx = spawn_callback_worker();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        for index in 0..100 {
+            let order = Arc::clone(&order);
+            callback_tx
+                .send(Box::new(move || order.lock().unwrap().push(index)))
+                .unwrap();
+        }
+        let (done_tx, done_rx) = std::sync::mpsc::channel();
+        callback_tx
+            .send(Box::new(move || done_tx.send(()).unwrap()))
+            .unwrap();
+        done_rx.recv_timeout(Duration::from_secs(1)).unwrap();
+
+        assert_eq!(*order.lock().unwrap(), (0..100).collect::<Vec<_>>());
     }
 }
@@ -0,0 +1,349 @@
+use super::DevicePool;
+use crate::inner::description::InputAliases;
+use crate::utils::{input_key, InputKey, JoystickState};
+use futures::{SinkExt, StreamExt};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio::sync::{broadcast, Notify};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Number of updates a slow client may fall behind before it starts skipping them.
+const CLIENT_BUFFER: usize = 64;
+
+/// A running WebSocket server started by [`DevicePool::serve_ws`].
+///
+/// Dropping the handle stops the server and closes all client connections.
+///
+/// # Fields
+///
+/// * `local_addr` - The address the server is listening on
+/// * `task` - The task accepting clients and broadcasting updates
+pub struct WsServer {
+    local_addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl WsServer {
+    /// Returns the address the server is listening on, useful when bound to port 0.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Stops the server and closes all client connections.
+    pub fn stop(self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for WsServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl DevicePool {
+    /// Serves the input states of the pool to WebSocket clients on `addr`.
+    ///
+    /// Every client first receives the current state of all devices, then one message
+    /// per register write containing only the devices whose state changed. Messages are
+    /// JSON objects mapping device names to their state, with the same keys as
+    /// `JoystickState.to_dict_keyed()` and the inputs keyed by their alias where the
+    /// description declares one. Hat directions are written as their variant names,
+    /// e.g. `"UpLeft"`, and `timestamp_us` is left out like in the dicts. The server
+    /// only reads the input register, so it does not interfere with `fetch` and keeps
+    /// running across `stop()`/`start()` of the pool.
+    ///
+    /// Clients that disconnect are dropped silently, clients that fall too far behind
+    /// skip the updates they missed.
+    ///
+    /// # Arguments
+    /// * `addr` - The address to listen on
+    ///
+    /// # Errors
+    /// Returns an error if the listener cannot be bound.
+    /// # Example
+    /// ```rust
+    /// let pool = DevicePool::new(vec!["device1.toml".to_string()], 0.1);
+    /// let server = pool.serve_ws("127.0.0.1:9001").await?;
+    /// ```
+    pub async fn serve_ws(&self, addr: impl ToSocketAddrs) -> std::io::Result<WsServer> {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let aliases = self
+            .devices
+            .iter()
+            .map(|desc| (desc.device_name.clone(), desc.aliases()))
+            .collect();
+        let task = tokio::spawn(broadcast_states(
+            listener,
+            aliases,
+            Arc::clone(&self.input_register),
+            Arc::clone(&self.register_version),
+            Arc::clone(&self.register_changed),
+        ));
+        Ok(WsServer { local_addr, task })
+    }
+}
+
+/// Accepts clients and broadcasts the changed devices after every register write.
+async fn broadcast_states(
+    listener: TcpListener,
+    aliases: HashMap<String, InputAliases>,
+    input_register: Arc<Mutex<HashMap<String, JoystickState>>>,
+    register_version: Arc<AtomicU64>,
+    register_changed: Arc<Notify>,
+) {
+    let (updates_tx, _) = broadcast::channel::<Arc<str>>(CLIENT_BUFFER);
+    let mut sent: HashMap<String, JoystickState> = input_register.lock().unwrap().clone();
+    let mut sent_version = register_version.load(Ordering::SeqCst);
+
+    loop {
+        // Register interest before checking, so a write in between is not missed
+        let changed = register_changed.notified();
+        tokio::pin!(changed);
+        changed.as_mut().enable();
+
+        let version = register_version.load(Ordering::SeqCst);
+        if version != sent_version {
+            sent_version = version;
+            let current = input_register.lock().unwrap().clone();
+            let updates = states_json(
+                current
+                    .iter()
+                    .filter(|(device_name, state)| sent.get(*device_name) != Some(*state)),
+                &aliases,
+            );
+            if !updates.is_empty() {
+                // Without clients there is nobody to receive the update
+                let _ = updates_tx.send(Value::Object(updates).to_string().into());
+            }
+            sent = current;
+        }
+
+        tokio::select! {
+            accepted = listener.accept() => {
+                if let Ok((stream, _)) = accepted {
+                    // Subscribe before taking the snapshot, so no update falls in between
+                    let updates_rx = updates_tx.subscribe();
+                    let snapshot = Value::Object(states_json(&sent, &aliases)).to_string();
+                    tokio::spawn(serve_client(stream, snapshot, updates_rx));
+                }
+            }
+            _ = changed => {}
+        }
+    }
+}
+
+/// Maps each device name to its state as written by [`state_json`].
+fn states_json<'a>(
+    states: impl IntoIterator<Item = (&'a String, &'a JoystickState)>,
+    aliases: &HashMap<String, InputAliases>,
+) -> Map<String, Value> {
+    states
+        .into_iter()
+        .map(|(device_name, state)| {
+            (
+                device_name.clone(),
+                state_json(state, aliases.get(device_name)),
+            )
+        })
+        .collect()
+}
+
+/// Converts a state into the JSON counterpart of `JoystickState::to_dict_keyed`.
+fn state_json(state: &JoystickState, aliases: Option<&InputAliases>) -> Value {
+    fn map_json<V: Clone + Into<Value>>(
+        values: &HashMap<u16, V>,
+        aliases: Option<&HashMap<u16, String>>,
+    ) -> Value {
+        values
+            .iter()
+            .map(|(code, value)| {
+                let key = match input_key(*code, aliases) {
+                    InputKey::Alias(alias) => alias,
+                    InputKey::Code(code) => code.to_string(),
+                };
+                (key, value.clone().into())
+            })
+            .collect::<Map<String, Value>>()
+            .into()
+    }
+
+    let directions: HashMap<u16, String> = state
+        .hat_directions()
+        .into_iter()
+        .map(|(code, direction)| (code, format!("{:?}", direction)))
+        .collect();
+    let axes = aliases.map(|a| &a.axes);
+    let buttons = aliases.map(|a| &a.buttons);
+    let hats = aliases.map(|a| &a.hats);
+    Value::Object(Map::from_iter([
+        ("axes".to_string(), map_json(&state.axes, axes)),
+        ("buttons".to_string(), map_json(&state.buttons, buttons)),
+        ("held".to_string(), map_json(&state.held, buttons)),
+        ("hats".to_string(), map_json(&state.hats, hats)),
+        ("raw_axes".to_string(), map_json(&state.raw_axes, axes)),
+        (
+            "button_hold_ms".to_string(),
+            map_json(&state.button_hold_ms, buttons),
+        ),
+        (
+            "pressed".to_string(),
+            map_json(&state.pressed_this_cycle, buttons),
+        ),
+        ("rel_axes".to_string(), map_json(&state.rel_axes, None)),
+        (
+            "axis_crossings".to_string(),
+            map_json(&state.axis_crossings, axes),
+        ),
+        (
+            "event_time_us".to_string(),
+            map_json(&state.event_time_us, None),
+        ),
+        ("directions".to_string(), map_json(&directions, hats)),
+    ]))
+}
+
+/// Sends `snapshot` and then every broadcast update to one client until it disconnects.
+async fn serve_client(
+    stream: TcpStream,
+    snapshot: String,
+    mut updates_rx: broadcast::Receiver<Arc<str>>,
+) {
+    let Ok(socket) = tokio_tungstenite::accept_async(stream).await else {
+        return;
+    };
+    let (mut sink, mut incoming) = socket.split();
+    if sink.send(Message::text(snapshot)).await.is_err() {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            update = updates_rx.recv() => match update {
+                Ok(json) => {
+                    if sink.send(Message::text(json.as_ref())).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            message = incoming.next() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Pings are answered by tungstenite, anything else is ignored
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    let _ = sink.close().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn write_state(pool: &DevicePool, device_name: &str, state: JoystickState) {
+        pool.input_register
+            .lock()
+            .unwrap()
+            .insert(device_name.to_string(), state);
+        pool.register_version.fetch_add(1, Ordering::SeqCst);
+        pool.register_changed.notify_waiters();
+    }
+
+    async fn next_json(
+        socket: &mut tokio_tungstenite::WebSocketStream<
+            tokio_tungstenite::MaybeTlsStream<TcpStream>,
+        >,
+    ) -> serde_json::Value {
+        let message = tokio::time::timeout(Duration::from_secs(2), socket.next())
+            .await
+            .expect("no message from server")
+            .unwrap()
+            .unwrap();
+        serde_json::from_str(message.to_text().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_serve_ws_broadcasts_changed_devices() {
+        let pool = DevicePool::from_descriptions(Vec::new(), 0.0, false);
+        let mut idle = JoystickState::new();
+        idle.axes.insert(0, 0.0);
+        write_state(&pool, "stick", idle.clone());
+        write_state(&pool, "throttle", idle);
+
+        let server = pool.serve_ws("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", server.local_addr());
+        let (mut first, _) = tokio_tungstenite::connect_async(url.as_str())
+            .await
+            .unwrap();
+        let (mut second, _) = tokio_tungstenite::connect_async(url.as_str())
+            .await
+            .unwrap();
+
+        let snapshot = next_json(&mut first).await;
+        assert!(snapshot.get("stick").is_some() && snapshot.get("throttle").is_some());
+        next_json(&mut second).await;
+
+        // A disconnected client must not disturb the others
+        second.close(None).await.unwrap();
+
+        let mut moved = JoystickState::new();
+        moved.axes.insert(0, 0.5);
+        write_state(&pool, "stick", moved);
+
+        let update = next_json(&mut first).await;
+        assert_eq!(update["stick"]["axes"]["0"], 0.5);
+        assert!(update.get("throttle").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_serve_ws_uses_dict_keys_and_aliases() {
+        let description = crate::inner::description::DeviceDescription::from_toml_str_rust(
+            r#"
+device_name = "stick"
+
+[[axes]]
+code = 0
+alias = "roll"
+
+[[buttons]]
+code = 288
+
+[[hats]]
+code = 16
+"#,
+        )
+        .unwrap();
+        let pool = DevicePool::from_descriptions(vec![description], 0.0, false);
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.25);
+        state.buttons.insert(288, 1);
+        state.pressed_this_cycle.insert(288, true);
+        state.hats.insert(16, -1);
+        write_state(&pool, "stick", state);
+
+        let server = pool.serve_ws("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", server.local_addr());
+        let (mut client, _) = tokio_tungstenite::connect_async(url.as_str())
+            .await
+            .unwrap();
+
+        let snapshot = next_json(&mut client).await;
+        let stick = &snapshot["stick"];
+        assert_eq!(stick["axes"]["roll"], 0.25);
+        assert_eq!(stick["buttons"]["288"], 1);
+        assert_eq!(stick["pressed"]["288"], true);
+        assert_eq!(stick["directions"]["16"], "Left");
+        assert!(stick.get("pressed_this_cycle").is_none());
+        assert!(stick.get("timestamp_us").is_none());
+        server.stop();
+    }
+}
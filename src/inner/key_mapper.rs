@@ -0,0 +1,322 @@
+use crate::inner::device_pool::{DevicePool, DevicePoolError};
+use crate::utils::JoystickState;
+use evdev::uinput::VirtualDevice;
+use evdev::{AttributeSet, EventType, InputEvent, KeyCode};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// How long `run` waits for a change before checking the bindings again.
+///
+/// Transitions that fall inside a debounce window are only retried on the next check,
+/// so without a timeout a release could be held back until the next input.
+pub const KEY_POLL: Duration = Duration::from_millis(20);
+
+/// Name of the virtual keyboard created by a [`KeyMapper`].
+const KEYBOARD_NAME: &str = "fly_stick key mapper";
+
+/// Maps joystick buttons onto the keys of a uinput virtual keyboard.
+///
+/// Each binding follows the level of a button (`JoystickState::held`): pressing the
+/// button presses the key, releasing it releases the key. A tap that was pressed and
+/// released between two fetches still produces a key press and release. A transition
+/// is ignored while the last transition of the same binding is less than the debounce
+/// time of the button ago, see [`DevicePool::debounce_for`].
+///
+/// # Fields
+///
+/// * `bindings` - The key bound to each `(device_name, button_code)`
+/// * `pressed` - The bindings whose key is currently pressed
+/// * `last_change` - When each binding last changed its key
+/// * `keyboard` - The virtual keyboard, created on first use
+pub struct KeyMapper {
+    bindings: BTreeMap<(String, u16), KeyCode>,
+    pressed: HashSet<(String, u16)>,
+    last_change: HashMap<(String, u16), Instant>,
+    keyboard: Option<VirtualDevice>,
+}
+
+impl Default for KeyMapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyMapper {
+    /// Creates a key mapper without bindings.
+    pub fn new() -> Self {
+        KeyMapper {
+            bindings: BTreeMap::new(),
+            pressed: HashSet::new(),
+            last_change: HashMap::new(),
+            keyboard: None,
+        }
+    }
+
+    /// Binds a button of `device_name` to `key`, replacing an earlier binding of it.
+    ///
+    /// The virtual keyboard is recreated on the next use, so it exposes the new key.
+    ///
+    /// # Arguments
+    ///
+    /// * `device_name` - The device the button belongs to
+    /// * `button_code` - The evdev code of the button
+    /// * `key` - The key to press while the button is held
+    pub fn add_binding(&mut self, device_name: &str, button_code: u16, key: KeyCode) {
+        self.bindings
+            .insert((device_name.to_string(), button_code), key);
+        self.keyboard = None;
+    }
+
+    /// Translates the button levels in `states` into key events.
+    ///
+    /// Bindings of devices missing from `states` keep their key state.
+    ///
+    /// # Arguments
+    ///
+    /// * `states` - The states of a pool fetch, keyed by device name
+    /// * `debounce` - The debounce time of a `(device_name, button_code)`
+    /// * `now` - The time of the fetch
+    ///
+    /// # Returns
+    /// The key events in binding order, without the closing `SYN_REPORT`.
+    pub fn translate(
+        &mut self,
+        states: &HashMap<String, JoystickState>,
+        debounce: impl Fn(&str, u16) -> Duration,
+        now: Instant,
+    ) -> Vec<InputEvent> {
+        let mut events = Vec::new();
+
+        for (binding, key) in &self.bindings {
+            let (device_name, code) = binding;
+            let Some(state) = states.get(device_name) else {
+                continue;
+            };
+            let level = state.held.get(code) == Some(&1);
+            let was_pressed = self.pressed.contains(binding);
+            let tapped =
+                !level && !was_pressed && state.pressed_this_cycle.get(code) == Some(&true);
+            if level == was_pressed && !tapped {
+                continue;
+            }
+
+            let debounce_time = debounce(device_name, *code);
+            if self
+                .last_change
+                .get(binding)
+                .is_some_and(|&changed_at| now.duration_since(changed_at) < debounce_time)
+            {
+                continue;
+            }
+            self.last_change.insert(binding.clone(), now);
+
+            if level != was_pressed {
+                events.push(key_event(*key, level));
+                if level {
+                    self.pressed.insert(binding.clone());
+                } else {
+                    self.pressed.remove(binding);
+                }
+            } else {
+                events.push(key_event(*key, true));
+                events.push(key_event(*key, false));
+            }
+        }
+
+        events
+    }
+
+    /// Translates `states` with the debounce times of `pool` and emits the key events.
+    ///
+    /// # Errors
+    ///
+    /// * `std::io::Error` - If the virtual keyboard cannot be created or written to
+    pub fn apply(
+        &mut self,
+        states: &HashMap<String, JoystickState>,
+        pool: &DevicePool,
+    ) -> Result<(), std::io::Error> {
+        let events = self.translate(
+            states,
+            |device_name, code| pool.debounce_for(device_name, code),
+            Instant::now(),
+        );
+        self.emit(&events)
+    }
+
+    /// Releases every key that is currently pressed.
+    ///
+    /// # Errors
+    ///
+    /// * `std::io::Error` - If the virtual keyboard cannot be written to
+    pub fn release_all(&mut self) -> Result<(), std::io::Error> {
+        let mut events = Vec::new();
+        for binding in self.pressed.drain() {
+            if let Some(&key) = self.bindings.get(&binding) {
+                events.push(key_event(key, false));
+            }
+        }
+        self.emit(&events)
+    }
+
+    /// Drives the key mapper from `pool` until monitoring stops.
+    ///
+    /// The states are read with [`DevicePool::fetch_or_current`] every [`KEY_POLL`], so
+    /// the triggers of the pool are consumed like by any other fetch. Keys that are still
+    /// pressed when monitoring stops are released.
+    ///
+    /// # Errors
+    /// Returns [`DevicePoolError::NotRunning`] if the pool is not monitoring, or an I/O
+    /// error if the virtual keyboard cannot be created or written to.
+    /// # Example
+    /// ```rust
+    /// let mut mapper = KeyMapper::new();
+    /// mapper.add_binding("Thrustmaster T.16000M", 288, KeyCode::KEY_SPACE);
+    /// mapper.run(&pool).await?;
+    /// ```
+    pub async fn run(&mut self, pool: &DevicePool) -> Result<(), DevicePoolError> {
+        if !pool.is_running() {
+            return Err(DevicePoolError::NotRunning);
+        }
+        while pool.is_running() {
+            let states = pool.fetch_or_current(Some(KEY_POLL), None).await?;
+            self.apply(&states, pool)?;
+        }
+        self.release_all()?;
+        Ok(())
+    }
+
+    /// Writes `events` to the virtual keyboard, creating it if needed.
+    fn emit(&mut self, events: &[InputEvent]) -> Result<(), std::io::Error> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        let keyboard = match &mut self.keyboard {
+            Some(keyboard) => keyboard,
+            None => self.keyboard.insert(self.build_keyboard()?),
+        };
+        keyboard.emit(events)
+    }
+
+    /// Creates a virtual keyboard exposing the bound keys.
+    fn build_keyboard(&self) -> Result<VirtualDevice, std::io::Error> {
+        let mut keys = AttributeSet::<KeyCode>::new();
+        for &key in self.bindings.values() {
+            keys.insert(key);
+        }
+        VirtualDevice::builder()?
+            .name(KEYBOARD_NAME)
+            .with_keys(&keys)?
+            .build()
+    }
+}
+
+/// Builds a key press (`pressed`) or release event.
+fn key_event(key: KeyCode, pressed: bool) -> InputEvent {
+    InputEvent::new(EventType::KEY.0, key.code(), i32::from(pressed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn states(held: u8, pressed_this_cycle: bool) -> HashMap<String, JoystickState> {
+        let mut state = JoystickState::new();
+        state.held.insert(288, held);
+        state.pressed_this_cycle.insert(288, pressed_this_cycle);
+        HashMap::from([("stick".to_string(), state)])
+    }
+
+    fn keys(events: &[InputEvent]) -> Vec<(u16, i32)> {
+        events
+            .iter()
+            .map(|event| (event.code(), event.value()))
+            .collect()
+    }
+
+    #[test]
+    fn test_translate_follows_button_level() {
+        let mut mapper = KeyMapper::new();
+        mapper.add_binding("stick", 288, KeyCode::KEY_SPACE);
+        let space = KeyCode::KEY_SPACE.code();
+        let no_debounce = |_: &str, _: u16| Duration::ZERO;
+        let now = Instant::now();
+
+        let events = mapper.translate(&states(1, true), no_debounce, now);
+        assert_eq!(keys(&events), vec![(space, 1)]);
+        // Holding the button does not repeat the press
+        assert!(mapper
+            .translate(&states(1, false), no_debounce, now)
+            .is_empty());
+        let events = mapper.translate(&states(0, false), no_debounce, now);
+        assert_eq!(keys(&events), vec![(space, 0)]);
+        // A tap between two fetches presses and releases the key
+        let events = mapper.translate(&states(0, true), no_debounce, now);
+        assert_eq!(keys(&events), vec![(space, 1), (space, 0)]);
+        // Unbound devices are ignored
+        let mut other = states(1, true);
+        other.insert("throttle".to_string(), other["stick"].clone());
+        other.remove("stick");
+        assert!(mapper.translate(&other, no_debounce, now).is_empty());
+    }
+
+    #[test]
+    fn test_translate_respects_debounce() {
+        let mut mapper = KeyMapper::new();
+        mapper.add_binding("stick", 288, KeyCode::KEY_SPACE);
+        let debounce = |_: &str, _: u16| Duration::from_millis(50);
+        let start = Instant::now();
+
+        assert_eq!(mapper.translate(&states(1, true), debounce, start).len(), 1);
+        // The bounce back to released is suppressed, then retried once the window passed
+        let bounce = start + Duration::from_millis(10);
+        assert!(mapper
+            .translate(&states(0, false), debounce, bounce)
+            .is_empty());
+        let later = start + Duration::from_millis(60);
+        let events = mapper.translate(&states(0, false), debounce, later);
+        assert_eq!(keys(&events), vec![(KeyCode::KEY_SPACE.code(), 0)]);
+    }
+
+    #[tokio::test]
+    async fn test_key_mapper_emits_mapped_key() {
+        let mut mapper = KeyMapper::new();
+        mapper.add_binding("stick", 288, KeyCode::KEY_SPACE);
+        let keyboard = match mapper.build_keyboard() {
+            Ok(keyboard) => mapper.keyboard.insert(keyboard),
+            Err(e) => {
+                // Creating uinput devices needs access to /dev/uinput
+                eprintln!("Skipping key mapper test: {}", e);
+                return;
+            }
+        };
+        let path = keyboard
+            .enumerate_dev_nodes_blocking()
+            .unwrap()
+            .next()
+            .expect("virtual keyboard has no device node")
+            .unwrap();
+        let mut events = evdev::Device::open(path)
+            .unwrap()
+            .into_event_stream()
+            .unwrap();
+
+        let pressed = mapper.translate(&states(1, true), |_, _| Duration::ZERO, Instant::now());
+        mapper.emit(&pressed).unwrap();
+
+        let event = loop {
+            let event = tokio::time::timeout(Duration::from_secs(2), events.next_event())
+                .await
+                .expect("no event from virtual keyboard")
+                .unwrap();
+            if event.event_type() == EventType::KEY {
+                break event;
+            }
+        };
+        assert_eq!(
+            (event.code(), event.value()),
+            (KeyCode::KEY_SPACE.code(), 1)
+        );
+        mapper.release_all().unwrap();
+    }
+}
@@ -1,3 +1,7 @@
+pub mod combo;
 pub mod description;
 pub mod device_pool;
 pub mod joystick;
+pub mod key_mapper;
+pub mod sdl_mapping;
+pub mod virtual_joystick;
@@ -0,0 +1,304 @@
+use crate::inner::description::{DeviceDescription, DeviceItem};
+
+/// First button code of the generic HID joystick layout (`BTN_JOYSTICK`).
+const BTN_JOYSTICK: u16 = 0x120;
+/// Code of the X axis of the first hat (`ABS_HAT0X`).
+const ABS_HAT0X: u16 = 0x10;
+/// Code of the Y axis of the last hat (`ABS_HAT3Y`).
+const ABS_HAT3Y: u16 = 0x17;
+
+/// SDL hat directions whose bit lies on the Y axis of a hat (up = 1, down = 4).
+const HAT_Y_BITS: u8 = 0x1 | 0x4;
+
+impl DeviceDescription {
+    /// Creates a description from one line of SDL's `gamecontrollerdb.txt`.
+    ///
+    /// A mapping line reads `GUID,name,output:input,...`, for example
+    /// `a:b0,leftx:a0,dpup:h0.1`. The outputs (`a`, `b`, `x`, `y`, `leftx`, `lefttrigger`,
+    /// `dpup`, ...) become the aliases of the inputs they are mapped onto:
+    ///
+    /// * `bN` is the N-th button of the device
+    /// * `aN` is the N-th absolute axis that is not a hat, a trailing `~` inverts it
+    /// * `hH.M` is hat H, the direction mask M selects its X (`dpleft`, `dpright`) or
+    ///   Y (`dpup`, `dpdown`) axis, which are aliased `dpadx` and `dpady`
+    ///
+    /// SDL numbers the inputs in the order it finds them on the device, so the codes
+    /// are only exact when `device` lists the inputs of the actual controller, e.g. from
+    /// [`DeviceDescription::from_device_rust`]. Without it, the inputs are assumed to be
+    /// contiguous like on generic HID joysticks: buttons from `BTN_JOYSTICK` (288) and
+    /// axes from `ABS_X` (0). Half-axis prefixes (`+`/`-`) are dropped, `platform` and
+    /// other unknown fields are ignored. USB vendor and product IDs are taken from the
+    /// GUID when it carries them.
+    ///
+    /// # Arguments
+    /// * `line` - The mapping line
+    /// * `device` - A description listing the inputs of the mapped device
+    ///
+    /// # Errors
+    /// Returns an error if the line has no GUID or name, or contains a malformed input
+    /// or an index the device does not have.
+    pub fn from_sdl_mapping_rust(
+        line: &str,
+        device: Option<&DeviceDescription>,
+    ) -> Result<Self, String> {
+        let mut fields = line.trim().split(',');
+        let guid = fields.next().unwrap_or_default().trim();
+        if guid.len() != 32 || !guid.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("Invalid SDL mapping GUID '{}'", guid));
+        }
+        let name = fields
+            .next()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| "SDL mapping has no device name".to_string())?;
+
+        let layout = SdlLayout::new(device);
+        let mut desc = DeviceDescription::new(
+            Some(name.to_string()),
+            None,
+            None,
+            Some(format!("Imported from SDL mapping {}", guid)),
+            None,
+            None,
+            None,
+        );
+        if let Some((vendor_id, product_id)) = guid_usb_ids(guid) {
+            desc.vendor_id = Some(vendor_id);
+            desc.product_id = Some(product_id);
+        }
+
+        for field in fields {
+            let Some((output, input)) = field.trim().split_once(':') else {
+                continue;
+            };
+            let output = output.trim_start_matches(['+', '-']);
+            let input = input.trim().trim_start_matches(['+', '-']);
+            if output == "platform" || input.is_empty() {
+                continue;
+            }
+
+            let malformed = || format!("Malformed SDL input '{}' for '{}'", input, output);
+            if let Some(index) = input.strip_prefix('b') {
+                let index = index.parse::<usize>().map_err(|_| malformed())?;
+                let code = layout.button(index).ok_or_else(|| out_of_range(input))?;
+                push_item(
+                    &mut desc.buttons,
+                    DeviceItem::new(code, Some(output.into())),
+                );
+            } else if let Some(index) = input.strip_prefix('a') {
+                let (index, invert) = match index.strip_suffix('~') {
+                    Some(index) => (index, true),
+                    None => (index, false),
+                };
+                let index = index.parse::<usize>().map_err(|_| malformed())?;
+                let code = layout.axis(index).ok_or_else(|| out_of_range(input))?;
+                let mut item = DeviceItem::new(code, Some(output.into()));
+                item.invert = invert;
+                push_item(&mut desc.axes, item);
+            } else if let Some(hat) = input.strip_prefix('h') {
+                let (index, mask) = hat.split_once('.').ok_or_else(malformed)?;
+                let index = index.parse::<usize>().map_err(|_| malformed())?;
+                let mask = mask.parse::<u8>().map_err(|_| malformed())?;
+                let hat_x = layout.hat(index).ok_or_else(|| out_of_range(input))?;
+                let (code, alias) = if mask & HAT_Y_BITS != 0 {
+                    (hat_x + 1, hat_alias(output, "dpady"))
+                } else {
+                    (hat_x, hat_alias(output, "dpadx"))
+                };
+                push_item(&mut desc.hats, DeviceItem::new(code, Some(alias)));
+            }
+        }
+
+        Ok(desc)
+    }
+}
+
+/// The codes SDL numbers its button, axis and hat indices with.
+struct SdlLayout {
+    buttons: Option<Vec<u16>>,
+    axes: Option<Vec<u16>>,
+    hats: Option<Vec<u16>>,
+}
+
+impl SdlLayout {
+    /// Sorts the inputs of `device` into SDL's enumeration order.
+    ///
+    /// Buttons start at `BTN_JOYSTICK` and wrap around to the codes below it, axes skip
+    /// the hat codes and each hat is identified by its X axis.
+    fn new(device: Option<&DeviceDescription>) -> Self {
+        let Some(device) = device else {
+            return SdlLayout {
+                buttons: None,
+                axes: None,
+                hats: None,
+            };
+        };
+
+        let mut buttons: Vec<u16> = device.buttons.iter().map(|item| item.code).collect();
+        buttons.sort_by_key(|&code| (code < BTN_JOYSTICK, code));
+        buttons.dedup();
+
+        let mut axes: Vec<u16> = device
+            .axes
+            .iter()
+            .map(|item| item.code)
+            .filter(|code| !is_hat(*code))
+            .collect();
+        axes.sort_unstable();
+        axes.dedup();
+
+        let mut hats: Vec<u16> = device
+            .hats
+            .iter()
+            .chain(&device.axes)
+            .map(|item| item.code)
+            .filter(|&code| is_hat(code))
+            .map(|code| code & !1)
+            .collect();
+        hats.sort_unstable();
+        hats.dedup();
+
+        SdlLayout {
+            buttons: Some(buttons),
+            axes: Some(axes),
+            hats: Some(hats),
+        }
+    }
+
+    fn button(&self, index: usize) -> Option<u16> {
+        match &self.buttons {
+            Some(codes) => codes.get(index).copied(),
+            None => u16::try_from(usize::from(BTN_JOYSTICK) + index).ok(),
+        }
+    }
+
+    fn axis(&self, index: usize) -> Option<u16> {
+        match &self.axes {
+            Some(codes) => codes.get(index).copied(),
+            None => {
+                let code = if index < usize::from(ABS_HAT0X) {
+                    index
+                } else {
+                    index + usize::from(ABS_HAT3Y - ABS_HAT0X + 1)
+                };
+                u16::try_from(code).ok()
+            }
+        }
+    }
+
+    fn hat(&self, index: usize) -> Option<u16> {
+        match &self.hats {
+            Some(codes) => codes.get(index).copied(),
+            None => (index < 4).then(|| ABS_HAT0X + 2 * index as u16),
+        }
+    }
+}
+
+fn is_hat(code: u16) -> bool {
+    (ABS_HAT0X..=ABS_HAT3Y).contains(&code)
+}
+
+/// Extracts the USB vendor and product ID from an SDL GUID.
+///
+/// GUIDs carrying them read `bus, crc, vendor, 0, product, 0, version, ...` as
+/// little-endian 16-bit words.
+fn guid_usb_ids(guid: &str) -> Option<(u16, u16)> {
+    let word = |index: usize| {
+        let bytes = u16::from_str_radix(&guid[index * 4..index * 4 + 4], 16).ok()?;
+        Some(bytes.swap_bytes())
+    };
+    let (vendor_id, product_id) = (word(2)?, word(4)?);
+    if word(3)? != 0 || word(5)? != 0 || vendor_id == 0 {
+        return None;
+    }
+    Some((vendor_id, product_id))
+}
+
+/// Aliases a hat axis after the dpad, or after `output` for anything but a dpad.
+fn hat_alias(output: &str, dpad_alias: &str) -> String {
+    if output.starts_with("dp") {
+        dpad_alias.to_string()
+    } else {
+        output.to_string()
+    }
+}
+
+/// Adds `item` unless its code is already mapped, the first mapping of a code wins.
+fn push_item(items: &mut Vec<DeviceItem>, item: DeviceItem) {
+    if !items.iter().any(|existing| existing.code == item.code) {
+        items.push(item);
+    }
+}
+
+fn out_of_range(input: &str) -> String {
+    format!("SDL input '{}' does not exist on the device", input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XBOX_360: &str = "030000005e0400008e02000014010000,Xbox 360 Controller,a:b0,b:b1,back:b6,dpdown:h0.4,dpleft:h0.8,dpright:h0.2,dpup:h0.1,guide:b8,leftshoulder:b4,leftstick:b9,lefttrigger:a2,leftx:a0,lefty:a1,rightshoulder:b5,rightstick:b10,righttrigger:a5,rightx:a3,righty:a4~,start:b7,x:b2,y:b3,platform:Linux,";
+
+    #[test]
+    fn test_from_sdl_mapping_assigns_aliases() {
+        let desc = DeviceDescription::from_sdl_mapping_rust(XBOX_360, None).unwrap();
+
+        assert_eq!(desc.device_name, "Xbox 360 Controller");
+        assert_eq!(
+            (desc.vendor_id, desc.product_id),
+            (Some(0x045e), Some(0x028e))
+        );
+
+        assert_eq!(desc.button_code("a"), Some(288));
+        assert_eq!(desc.button_code("y"), Some(291));
+        assert_eq!(desc.button_code("rightstick"), Some(298));
+        assert_eq!(desc.axis_code("leftx"), Some(0));
+        assert_eq!(desc.axis_code("lefttrigger"), Some(2));
+        assert_eq!(desc.axis_code("righttrigger"), Some(5));
+        assert!(desc.axes.iter().any(|item| item.code == 4 && item.invert));
+        assert_eq!(desc.hat_code("dpadx"), Some(16));
+        assert_eq!(desc.hat_code("dpady"), Some(17));
+        assert_eq!(desc.hats.len(), 2);
+    }
+
+    #[test]
+    fn test_from_sdl_mapping_uses_device_layout() {
+        // An Xbox pad reports BTN_SOUTH.. with gaps, which SDL numbers densely
+        let codes = [304, 305, 307, 308, 310, 311, 314, 315, 316, 317, 318];
+        let device = DeviceDescription::new(
+            None,
+            None,
+            None,
+            None,
+            Some(
+                [0, 1, 2, 3, 4, 5, 16, 17]
+                    .map(|code| DeviceItem::new(code, None))
+                    .to_vec(),
+            ),
+            Some(codes.map(|code| DeviceItem::new(code, None)).to_vec()),
+            None,
+        );
+
+        let desc = DeviceDescription::from_sdl_mapping_rust(XBOX_360, Some(&device)).unwrap();
+
+        assert_eq!(desc.button_code("a"), Some(304));
+        assert_eq!(desc.button_code("x"), Some(307));
+        assert_eq!(desc.button_code("start"), Some(315));
+        assert_eq!(desc.axis_code("righty"), Some(4));
+        assert_eq!(desc.hat_code("dpady"), Some(17));
+    }
+
+    #[test]
+    fn test_from_sdl_mapping_rejects_malformed_lines() {
+        assert!(DeviceDescription::from_sdl_mapping_rust("not a guid,Pad,a:b0", None).is_err());
+        let guid = "03000000000000000000000000000000";
+        assert!(DeviceDescription::from_sdl_mapping_rust(guid, None).is_err());
+        let line = format!("{},Pad,a:bx", guid);
+        assert!(DeviceDescription::from_sdl_mapping_rust(&line, None).is_err());
+
+        let desc =
+            DeviceDescription::from_sdl_mapping_rust(&format!("{},Pad,a:b0", guid), None).unwrap();
+        assert_eq!(desc.vendor_id, None);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::inner::description::DeviceDescription;
+use crate::utils::JoystickState;
+use evdev::uinput::VirtualDevice;
+use evdev::{
+    AbsInfo, AbsoluteAxisCode, AttributeSet, EventType, InputEvent, KeyCode, UinputAbsSetup,
+};
+
+/// Raw range of the analog axes of a virtual joystick.
+pub const VIRTUAL_AXIS_RANGE: (i32, i32) = (-32768, 32767);
+
+/// A virtual joystick created through uinput.
+///
+/// The device exposes exactly the axes, buttons and hats of the description it was
+/// built from, so states read from real devices can be processed (remapped, mixed,
+/// ...) and written back out as a new controller.
+///
+/// # Fields
+///
+/// * `device` - The uinput device handle
+/// * `description` - The description defining the inputs of the device
+pub struct VirtualJoystick {
+    device: VirtualDevice,
+    description: DeviceDescription,
+}
+
+impl VirtualJoystick {
+    /// Creates a virtual joystick named after `description.device_name`.
+    ///
+    /// Analog axes get the range [`VIRTUAL_AXIS_RANGE`], hats the range `[-1, 1]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - The description whose axes, buttons and hats the device gets
+    ///
+    /// # Errors
+    ///
+    /// * `std::io::Error` - If `/dev/uinput` cannot be opened or the device cannot be created
+    pub fn new(description: &DeviceDescription) -> Result<Self, std::io::Error> {
+        let mut builder = VirtualDevice::builder()?.name(description.device_name.as_str());
+
+        if !description.buttons.is_empty() {
+            let mut keys = AttributeSet::<KeyCode>::new();
+            for button in &description.buttons {
+                keys.insert(KeyCode::new(button.code));
+            }
+            builder = builder.with_keys(&keys)?;
+        }
+
+        let (min, max) = VIRTUAL_AXIS_RANGE;
+        for axis in &description.axes {
+            let info = AbsInfo::new(0, min, max, 0, 0, 0);
+            builder = builder
+                .with_absolute_axis(&UinputAbsSetup::new(AbsoluteAxisCode(axis.code), info))?;
+        }
+        for hat in &description.hats {
+            let info = AbsInfo::new(0, -1, 1, 0, 0, 0);
+            builder = builder
+                .with_absolute_axis(&UinputAbsSetup::new(AbsoluteAxisCode(hat.code), info))?;
+        }
+
+        Ok(VirtualJoystick {
+            device: builder.build()?,
+            description: description.clone(),
+        })
+    }
+
+    /// Pushes `state` out as one report of the virtual device.
+    ///
+    /// See [`state_to_events`] for which inputs are written.
+    ///
+    /// # Errors
+    ///
+    /// * `std::io::Error` - If the events cannot be written to the device
+    pub fn emit(&mut self, state: &JoystickState) -> Result<(), std::io::Error> {
+        let events = state_to_events(&self.description, state);
+        if events.is_empty() {
+            return Ok(());
+        }
+        self.device.emit(&events)
+    }
+}
+
+/// Translates `state` into the input events of a virtual device built from `description`.
+///
+/// Only inputs declared in the description and present in the state are written, in
+/// declaration order. Axis values are read in the `normalize_range` of the description
+/// and scaled onto [`VIRTUAL_AXIS_RANGE`], hats are clamped to -1, 0 or 1 and any
+/// non-zero button value counts as pressed. The closing `SYN_REPORT` is left to the
+/// caller.
+pub fn state_to_events(description: &DeviceDescription, state: &JoystickState) -> Vec<InputEvent> {
+    let mut events = Vec::new();
+
+    for axis in &description.axes {
+        if let Some(&value) = state.axes.get(&axis.code) {
+            let raw = denormalize_axis(value, description.normalize_range);
+            events.push(InputEvent::new(EventType::ABSOLUTE.0, axis.code, raw));
+        }
+    }
+    for button in &description.buttons {
+        if let Some(&value) = state.buttons.get(&button.code) {
+            let pressed = i32::from(value != 0);
+            events.push(InputEvent::new(EventType::KEY.0, button.code, pressed));
+        }
+    }
+    for hat in &description.hats {
+        if let Some(&value) = state.hats.get(&hat.code) {
+            let value = i32::from(value.signum());
+            events.push(InputEvent::new(EventType::ABSOLUTE.0, hat.code, value));
+        }
+    }
+
+    events
+}
+
+/// Scales a value from `(min, max)` onto [`VIRTUAL_AXIS_RANGE`], clamping out-of-range values.
+///
+/// The middle of the range maps onto exactly 0, a degenerate range (`max <= min`) maps
+/// every value onto it.
+fn denormalize_axis(value: f32, (min, max): (f32, f32)) -> i32 {
+    let (raw_min, raw_max) = VIRTUAL_AXIS_RANGE;
+    let centered = if max > min {
+        ((value - min) / (max - min) * 2.0 - 1.0).clamp(-1.0, 1.0)
+    } else {
+        0.0
+    };
+    let scale = if centered < 0.0 { -raw_min } else { raw_max };
+    (centered * scale as f32).round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inner::description::DeviceItem;
+
+    fn virtual_description() -> DeviceDescription {
+        DeviceDescription::new(
+            Some("fly_stick test joystick".to_string()),
+            None,
+            None,
+            None,
+            Some(vec![DeviceItem::new(0, None), DeviceItem::new(1, None)]),
+            Some(vec![DeviceItem::new(288, None)]),
+            Some(vec![DeviceItem::new(16, None)]),
+        )
+    }
+
+    #[test]
+    fn test_state_to_events_translates_declared_inputs() {
+        let mut state = JoystickState::new();
+        state.axes.insert(0, -1.0);
+        state.axes.insert(1, 1.0);
+        state.axes.insert(5, 0.5);
+        state.buttons.insert(288, 1);
+        state.hats.insert(16, -1);
+
+        let events: Vec<(u16, u16, i32)> = state_to_events(&virtual_description(), &state)
+            .iter()
+            .map(|event| (event.event_type().0, event.code(), event.value()))
+            .collect();
+
+        let abs = EventType::ABSOLUTE.0;
+        assert_eq!(
+            events,
+            vec![
+                (abs, 0, -32768),
+                (abs, 1, 32767),
+                (EventType::KEY.0, 288, 1),
+                (abs, 16, -1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_denormalize_axis_honors_normalize_range() {
+        assert_eq!(denormalize_axis(0.5, (0.0, 1.0)), 0);
+        assert_eq!(denormalize_axis(127.0, (0.0, 127.0)), 32767);
+        assert_eq!(denormalize_axis(-2.0, (-1.0, 1.0)), -32768);
+        assert_eq!(denormalize_axis(3.0, (1.0, 1.0)), 0);
+    }
+
+    #[test]
+    fn test_virtual_joystick_emits_state() {
+        let mut joystick = match VirtualJoystick::new(&virtual_description()) {
+            Ok(joystick) => joystick,
+            Err(e) => {
+                // Creating uinput devices needs access to /dev/uinput
+                eprintln!("Skipping virtual joystick test: {}", e);
+                return;
+            }
+        };
+
+        let mut state = JoystickState::new();
+        state.axes.insert(0, 0.25);
+        state.buttons.insert(288, 1);
+        joystick.emit(&state).unwrap();
+    }
+}
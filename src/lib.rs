@@ -1,17 +1,33 @@
 pub mod inner;
 pub mod utils;
+#[cfg(feature = "python")]
 pub mod wrapper;
 
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "python")]
 #[pymodule]
 fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<wrapper::device_pool_wrapper::PyDevicePool>()?;
+    m.add_class::<wrapper::device_pool_wrapper::PyStateStream>()?;
+    m.add_class::<wrapper::device_pool_wrapper::PyActivityStream>()?;
     m.add_class::<wrapper::joystick_wrapper::PyJoystick>()?;
+    m.add_class::<wrapper::virtual_joystick_wrapper::PyVirtualJoystick>()?;
+    m.add_class::<wrapper::key_mapper_wrapper::PyKeyMapper>()?;
+    m.add_class::<wrapper::combo_wrapper::PyComboMatcher>()?;
+    m.add_class::<inner::device_pool::DeviceReport>()?;
+    m.add_class::<inner::device_pool::InputEvent>()?;
+    m.add_class::<inner::device_pool::InputKind>()?;
 
     m.add_class::<utils::JoystickInfo>()?;
     m.add_class::<utils::JoystickState>()?;
+    m.add_class::<utils::HatDirection>()?;
     m.add_function(wrap_pyfunction!(utils::fetch_connected_joysticks, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        utils::fetch_connected_joysticks_detailed,
+        m
+    )?)?;
 
     m.add_class::<inner::description::DeviceItem>()?;
     m.add_class::<inner::description::DeviceDescription>()?;
@@ -1,18 +1,80 @@
+#[cfg(feature = "python")]
+use crate::inner::description::InputAliases;
+use crate::inner::joystick::is_hat_axis;
+use evdev::{AbsoluteAxisCode, AttributeSetRef, FFEffectCode, KeyCode};
+#[cfg(feature = "python")]
 use pyo3::{prelude::*, types::PyDict};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::Instant;
 
 /// Joystick information containing path and name
-#[derive(Debug, Clone)]
-#[pyclass]
+///
+/// The capability fields are only filled in by [`fetch_connected_joysticks_detailed`],
+/// [`fetch_connected_joysticks`] leaves them at 0/false.
+#[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
 pub struct JoystickInfo {
-    #[pyo3(get, set)]
     pub path: String,
-    #[pyo3(get, set)]
     pub name: String,
+    /// Number of analog absolute axes, hats excluded
+    pub num_axes: usize,
+    /// Number of buttons/keys
+    pub num_buttons: usize,
+    /// Whether the device has at least one hat switch axis
+    pub has_hats: bool,
+    /// Whether the device supports any force feedback effect
+    pub has_ff: bool,
+    /// The unique ID reported by the device (often a serial number), if any
+    pub uniq: Option<String>,
+    /// The physical path of the device (e.g. the USB port), if any
+    pub phys: Option<String>,
+    /// The USB (or bus) vendor ID of the device
+    pub vendor_id: u16,
+    /// The USB (or bus) product ID of the device
+    pub product_id: u16,
 }
 
-#[derive(Debug, Clone)]
-#[pyclass]
+impl JoystickInfo {
+    /// Builds the info of the device opened at `path`, without capabilities.
+    fn from_device(path: &std::path::Path, device: &evdev::Device) -> Self {
+        let non_empty = |value: Option<&str>| value.filter(|v| !v.is_empty()).map(str::to_string);
+        JoystickInfo {
+            path: path.to_string_lossy().to_string(),
+            name: device.name().unwrap_or("Unknown").to_string(),
+            uniq: non_empty(device.unique_name()),
+            phys: non_empty(device.physical_path()),
+            vendor_id: device.input_id().vendor(),
+            product_id: device.input_id().product(),
+            ..JoystickInfo::default()
+        }
+    }
+
+    /// Fills in the capability fields from the capability sets reported by evdev.
+    pub fn with_capabilities(
+        self,
+        axes: Option<&AttributeSetRef<AbsoluteAxisCode>>,
+        keys: Option<&AttributeSetRef<KeyCode>>,
+        ff: Option<&AttributeSetRef<FFEffectCode>>,
+    ) -> Self {
+        let (hats, analog): (Vec<_>, Vec<_>) = axes
+            .into_iter()
+            .flat_map(|axes| axes.iter())
+            .partition(|&axis| is_hat_axis(axis));
+
+        JoystickInfo {
+            num_axes: analog.len(),
+            num_buttons: keys.map_or(0, |keys| keys.iter().count()),
+            has_hats: !hats.is_empty(),
+            has_ff: ff.is_some_and(|ff| ff.iter().next().is_some()),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(get_all, set_all))]
 /// Represents input data from a joystick or game controller device.
 ///
 /// This structure contains the current state of all input elements including
@@ -23,92 +85,734 @@ pub struct JoystickInfo {
 /// # Fields
 ///
 /// * `axes` - A mapping of axis identifiers to their normalized values (-1.0 to 1.0)
-/// * `buttons` - A mapping of button identifiers to their press state (0 = released, 1 = pressed)
-/// * `hats` - A mapping of hat identifiers to their directional state (bitmask representing direction)
+/// * `buttons` - A mapping of button identifiers to their press state (0 = released, 1 = pressed).
+///   In a pool this is a trigger that is cleared after every fetch
+/// * `held` - A mapping of button identifiers to their current level (0 = released,
+///   1 = pressed). Unlike `buttons` it is never cleared by a fetch and only changes when
+///   the device reports a press or release
+/// * `hats` - A mapping of hat axis identifiers to their direction (-1, 0 or 1). The X and Y
+///   axes of a hat are stored under separate codes, see [`JoystickState::hat_direction`]
+/// * `raw_axes` - A mapping of axis identifiers to the unnormalized values reported by evdev
+/// * `button_hold_ms` - A mapping of button identifiers to how long they have been held in
+///   milliseconds (0 when released)
+/// * `pressed_this_cycle` - A mapping of button identifiers to whether they went from released
+///   to pressed since the last fetch
+/// * `rel_axes` - A mapping of relative axis identifiers (EV_REL) to the sum of all deltas
+///   reported since the device was opened
+/// * `axis_crossings` - A mapping of axis identifiers to the direction in which the axis
+///   last crossed 0.0 since the last fetch (1 from negative to positive, -1 from positive
+///   to negative, 0 for none). Only axes declared with `track_crossings` are listed
+/// * `timestamp_us` - When the state was last updated by a monitor, in monotonic
+///   microseconds (see [`monotonic_us`]), 0 if it never was
+/// * `event_time_us` - A mapping of axis, button and hat identifiers to the kernel
+///   timestamp of their latest event, in microseconds since the Unix epoch. Only filled
+///   for devices described with `event_times = true`
+///
+/// # Serialization
+///
+/// The state (de)serializes with serde. Input codes are written as string keys so the
+/// maps also work in formats without integer keys, such as TOML.
 ///
 /// # Python Integration
 ///
 /// This struct is exposed to Python through PyO3, allowing direct access to all fields
 /// for reading and writing input state data.
 pub struct JoystickState {
-    #[pyo3(get, set)]
+    #[serde(default, with = "code_map")]
     pub axes: HashMap<u16, f32>,
-    #[pyo3(get, set)]
+    #[serde(default, with = "code_map")]
     pub buttons: HashMap<u16, u8>,
-    #[pyo3(get, set)]
+    #[serde(default, with = "code_map")]
+    pub held: HashMap<u16, u8>,
+    #[serde(default, with = "code_map")]
     pub hats: HashMap<u16, i8>,
+    #[serde(default, with = "code_map")]
+    pub raw_axes: HashMap<u16, i32>,
+    #[serde(default, with = "code_map")]
+    pub button_hold_ms: HashMap<u16, u64>,
+    #[serde(default, with = "code_map")]
+    pub pressed_this_cycle: HashMap<u16, bool>,
+    #[serde(default, with = "code_map")]
+    pub rel_axes: HashMap<u16, i32>,
+    #[serde(default, with = "code_map")]
+    pub axis_crossings: HashMap<u16, i8>,
+    #[serde(default)]
+    pub timestamp_us: u64,
+    #[serde(default, with = "code_map")]
+    pub event_time_us: HashMap<u16, u64>,
 }
 
-#[pymethods]
+#[cfg_attr(feature = "python", pymethods)]
 impl JoystickState {
     /// Creates a new JoystickState instance with empty input data.
+    #[cfg(feature = "python")]
     #[new]
-    pub fn new() -> Self {
-        JoystickState {
-            axes: HashMap::new(),
-            buttons: HashMap::new(),
-            hats: HashMap::new(),
-        }
+    fn py_new() -> Self {
+        Self::new()
     }
 
     pub fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
 
+    #[cfg(feature = "python")]
     pub fn to_dict(&self, py: Python) -> PyResult<PyObject> {
-        let dict = PyDict::new(py);
+        self.to_dict_keyed(py, None)
+    }
 
-        // Convert axes
-        let axes_dict = PyDict::new(py);
-        for (code, value) in &self.axes {
-            axes_dict.set_item(*code, *value)?;
+    /// Returns the combined `(x, y)` direction of a hat switch.
+    ///
+    /// Hats report their X and Y axes under separate codes, with the Y code directly
+    /// following the X code (e.g. `ABS_HAT0X` = 16 and `ABS_HAT0Y` = 17). `x` is -1 for
+    /// left and 1 for right, `y` is -1 for up and 1 for down, a missing axis reads as 0.
+    ///
+    /// # Arguments
+    /// * `base` - The code of the hat's X axis (16, 18, 20 or 22 for `ABS_HAT0X`..`ABS_HAT3X`)
+    ///
+    /// # Returns
+    /// The `(x, y)` direction, or `None` if neither axis of the hat is present.
+    pub fn hat_direction(&self, base: u16) -> Option<(i8, i8)> {
+        let x = self.hats.get(&base).copied();
+        let y = self.hats.get(&(base + 1)).copied();
+        if x.is_none() && y.is_none() {
+            return None;
         }
-        dict.set_item("axes", axes_dict)?;
+        Some((x.unwrap_or(0), y.unwrap_or(0)))
+    }
+
+    /// Returns the combined 8-way direction of a hat switch.
+    ///
+    /// Built from the same paired axes as [`JoystickState::hat_direction`], a hat that
+    /// is missing from the state reads as [`HatDirection::Center`].
+    ///
+    /// # Arguments
+    /// * `base` - The code of the hat's X axis (16, 18, 20 or 22 for `ABS_HAT0X`..`ABS_HAT3X`)
+    pub fn hat_as_direction(&self, base: u16) -> HatDirection {
+        let (x, y) = self.hat_direction(base).unwrap_or((0, 0));
+        HatDirection::from_axes(x, y)
+    }
+
+    /// Returns the normalized value of axis `code` remapped from `[-1, 1]` into
+    /// `[out_min, out_max]`, e.g. `1000.0..2000.0` for servo pulse widths.
+    ///
+    /// -1 maps to `out_min`, 0 to the midpoint and 1 to `out_max`. `out_min` may be
+    /// larger than `out_max` to flip the direction.
+    ///
+    /// # Arguments
+    /// * `code` - The evdev code of the axis
+    /// * `out_min` - The value -1 maps to
+    /// * `out_max` - The value 1 maps to
+    ///
+    /// # Returns
+    /// The remapped value, or `None` if the axis is not in the state.
+    pub fn rescale_axis(&self, code: u16, out_min: f32, out_max: f32) -> Option<f32> {
+        let value = *self.axes.get(&code)?;
+        Some(out_min + (value + 1.0) / 2.0 * (out_max - out_min))
+    }
 
-        // Convert buttons
-        let buttons_dict = PyDict::new(py);
-        for (code, value) in &self.buttons {
-            buttons_dict.set_item(*code, *value)?;
+    /// Returns the axes, buttons, held buttons, hats and relative axes that differ between
+    /// this state and `other`.
+    ///
+    /// `self` is treated as the earlier state and `other` as the later one, so the
+    /// result carries the values of `other`. An entry only present in `other` is
+    /// reported with its value, an entry only present in `self` is reported as
+    /// released/centred (0). The result is empty exactly when both states are equal.
+    ///
+    /// # Arguments
+    /// * `other` - The later state to compare against
+    pub fn diff(&self, other: &Self) -> JoystickState {
+        fn changed<V: Copy + PartialEq + Default>(
+            before: &HashMap<u16, V>,
+            after: &HashMap<u16, V>,
+        ) -> HashMap<u16, V> {
+            let mut changes: HashMap<u16, V> = after
+                .iter()
+                .filter(|(code, value)| before.get(code) != Some(value))
+                .map(|(&code, &value)| (code, value))
+                .collect();
+            for &code in before.keys() {
+                if !after.contains_key(&code) {
+                    changes.insert(code, V::default());
+                }
+            }
+            changes
+        }
+
+        JoystickState {
+            axes: changed(&self.axes, &other.axes),
+            buttons: changed(&self.buttons, &other.buttons),
+            held: changed(&self.held, &other.held),
+            hats: changed(&self.hats, &other.hats),
+            rel_axes: changed(&self.rel_axes, &other.rel_axes),
+            ..JoystickState::new()
+        }
+    }
+}
+
+impl JoystickState {
+    /// Creates a new JoystickState instance with empty input data.
+    pub fn new() -> Self {
+        JoystickState {
+            axes: HashMap::new(),
+            buttons: HashMap::new(),
+            held: HashMap::new(),
+            hats: HashMap::new(),
+            raw_axes: HashMap::new(),
+            button_hold_ms: HashMap::new(),
+            pressed_this_cycle: HashMap::new(),
+            rel_axes: HashMap::new(),
+            axis_crossings: HashMap::new(),
+            timestamp_us: 0,
+            event_time_us: HashMap::new(),
         }
-        dict.set_item("buttons", buttons_dict)?;
+    }
 
-        // Convert hats
-        let hats_dict = PyDict::new(py);
-        for (code, value) in &self.hats {
-            hats_dict.set_item(*code, *value)?;
+    /// Converts the state into a Python dict, optionally keying inputs by their alias.
+    ///
+    /// Without `aliases` every input is keyed by its numeric code. With `aliases`,
+    /// inputs that have an alias are keyed by it and the others keep their code.
+    #[cfg(feature = "python")]
+    pub fn to_dict_keyed(&self, py: Python, aliases: Option<&InputAliases>) -> PyResult<PyObject> {
+        fn insert_map<'py, V: Copy + IntoPyObject<'py>>(
+            dict: &Bound<'py, PyDict>,
+            name: &str,
+            values: &HashMap<u16, V>,
+            aliases: Option<&HashMap<u16, String>>,
+        ) -> PyResult<()> {
+            let map_dict = PyDict::new(dict.py());
+            for (code, value) in values {
+                map_dict.set_item(input_key(*code, aliases), *value)?;
+            }
+            dict.set_item(name, map_dict)
         }
-        dict.set_item("hats", hats_dict)?;
 
+        let dict = PyDict::new(py);
+        insert_map(&dict, "axes", &self.axes, aliases.map(|a| &a.axes))?;
+        insert_map(&dict, "buttons", &self.buttons, aliases.map(|a| &a.buttons))?;
+        insert_map(&dict, "held", &self.held, aliases.map(|a| &a.buttons))?;
+        insert_map(&dict, "hats", &self.hats, aliases.map(|a| &a.hats))?;
+        insert_map(&dict, "raw_axes", &self.raw_axes, aliases.map(|a| &a.axes))?;
+        insert_map(
+            &dict,
+            "button_hold_ms",
+            &self.button_hold_ms,
+            aliases.map(|a| &a.buttons),
+        )?;
+        insert_map(
+            &dict,
+            "pressed",
+            &self.pressed_this_cycle,
+            aliases.map(|a| &a.buttons),
+        )?;
+        insert_map(&dict, "rel_axes", &self.rel_axes, None)?;
+        insert_map(
+            &dict,
+            "axis_crossings",
+            &self.axis_crossings,
+            aliases.map(|a| &a.axes),
+        )?;
+        insert_map(&dict, "event_time_us", &self.event_time_us, None)?;
+        insert_map(
+            &dict,
+            "directions",
+            &self.hat_directions(),
+            aliases.map(|a| &a.hats),
+        )?;
         Ok(dict.into())
     }
+
+    /// Returns the combined direction of every hat in the state, keyed by its X axis code.
+    ///
+    /// Only the `ABS_HAT0X`..`ABS_HAT3Y` hats are combined, other hat codes have no pair.
+    pub fn hat_directions(&self) -> HashMap<u16, HatDirection> {
+        let first = AbsoluteAxisCode::ABS_HAT0X.0;
+        self.hats
+            .keys()
+            .filter(|&&code| is_hat_axis(AbsoluteAxisCode(code)))
+            .map(|&code| first + (code - first) / 2 * 2)
+            .map(|base| (base, self.hat_as_direction(base)))
+            .collect()
+    }
+
+    /// Compares two states like `==`, but treats axes as equal while their values differ
+    /// by less than `axis_epsilon`.
+    ///
+    /// Buttons, held buttons, hats and relative axes are compared exactly, and both
+    /// states must report the same axes. An `axis_epsilon` of 0.0 is plain `==`.
+    pub fn approx_eq(&self, other: &Self, axis_epsilon: f32) -> bool {
+        let axes_close = self.axes.len() == other.axes.len()
+            && self.axes.iter().all(|(code, &value)| {
+                other
+                    .axes
+                    .get(code)
+                    .is_some_and(|&other| value == other || (value - other).abs() < axis_epsilon)
+            });
+        axes_close
+            && self.buttons == other.buttons
+            && self.held == other.held
+            && self.hats == other.hats
+            && self.rel_axes == other.rel_axes
+    }
+
+    /// Applies the inputs reported in `delta` on top of this state.
+    ///
+    /// Values present in `delta` overwrite the stored ones, everything else is kept.
+    /// `pressed_this_cycle` only describes the latest read, so it is replaced as a whole.
+    pub fn merge_delta(&mut self, delta: &JoystickState) {
+        self.axes.extend(&delta.axes);
+        self.raw_axes.extend(&delta.raw_axes);
+        self.buttons.extend(&delta.buttons);
+        self.held.extend(&delta.held);
+        self.hats.extend(&delta.hats);
+        self.rel_axes.extend(&delta.rel_axes);
+        self.button_hold_ms.extend(&delta.button_hold_ms);
+        self.pressed_this_cycle
+            .clone_from(&delta.pressed_this_cycle);
+        self.axis_crossings.extend(&delta.axis_crossings);
+        self.event_time_us.extend(&delta.event_time_us);
+        if delta.timestamp_us != 0 {
+            self.timestamp_us = delta.timestamp_us;
+        }
+    }
+
+    /// Returns true if the state carries no axis, button, hat or relative axis values.
+    pub fn is_empty(&self) -> bool {
+        self.axes.is_empty()
+            && self.buttons.is_empty()
+            && self.held.is_empty()
+            && self.hats.is_empty()
+            && self.rel_axes.is_empty()
+    }
 }
 
-// Implement PartialEq for JoystickState to enable comparison
+impl Default for JoystickState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Implement PartialEq for JoystickState to enable comparison.
+// Raw axis values, hold durations, press edges and axis crossings are left out, they
+// only mirror the other inputs. The timestamps are left out so a mere update is not a change.
 impl PartialEq for JoystickState {
     fn eq(&self, other: &Self) -> bool {
-        self.axes == other.axes && self.buttons == other.buttons && self.hats == other.hats
+        self.axes == other.axes
+            && self.buttons == other.buttons
+            && self.held == other.held
+            && self.hats == other.hats
+            && self.rel_axes == other.rel_axes
+    }
+}
+
+/// Serde adapter writing the code-keyed maps of a [`JoystickState`] with string keys.
+mod code_map {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::{BTreeMap, HashMap};
+
+    pub fn serialize<S: Serializer, V: Serialize>(
+        map: &HashMap<u16, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let sorted: BTreeMap<u16, &V> = map.iter().map(|(code, value)| (*code, value)).collect();
+        serializer.collect_map(
+            sorted
+                .into_iter()
+                .map(|(code, value)| (code.to_string(), value)),
+        )
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, V: Deserialize<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<u16, V>, D::Error> {
+        HashMap::<String, V>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(code, value)| {
+                let code = code
+                    .parse()
+                    .map_err(|_| D::Error::custom(format!("invalid input code {:?}", code)))?;
+                Ok((code, value))
+            })
+            .collect()
+    }
+}
+
+/// Returns the microseconds elapsed on a monotonic clock since its first use in the process.
+///
+/// Only differences between two readings are meaningful, which is what input recording
+/// and latency measurements need. Readings are at least 1, so 0 can mean "never".
+pub fn monotonic_us() -> u64 {
+    static START: OnceLock<Instant> = OnceLock::new();
+    (START.get_or_init(Instant::now).elapsed().as_micros() as u64).max(1)
+}
+
+/// The combined direction of a hat switch, see [`JoystickState::hat_as_direction`].
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HatDirection {
+    Center,
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl HatDirection {
+    /// Maps the `(x, y)` axis values of a hat (-1 left/up, 1 right/down) to a direction.
+    pub fn from_axes(x: i8, y: i8) -> Self {
+        match (x.signum(), y.signum()) {
+            (0, -1) => HatDirection::Up,
+            (1, -1) => HatDirection::UpRight,
+            (1, 0) => HatDirection::Right,
+            (1, 1) => HatDirection::DownRight,
+            (0, 1) => HatDirection::Down,
+            (-1, 1) => HatDirection::DownLeft,
+            (-1, 0) => HatDirection::Left,
+            (-1, -1) => HatDirection::UpLeft,
+            _ => HatDirection::Center,
+        }
     }
 }
 
+/// The key of an input in a dict produced by [`JoystickState::to_dict_keyed`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "python", derive(IntoPyObject))]
+pub enum InputKey {
+    Alias(String),
+    Code(u16),
+}
+
+/// Returns the alias of `code` if one is defined in `aliases`, otherwise the code itself.
+pub fn input_key(code: u16, aliases: Option<&HashMap<u16, String>>) -> InputKey {
+    match aliases.and_then(|aliases| aliases.get(&code)) {
+        Some(alias) => InputKey::Alias(alias.clone()),
+        None => InputKey::Code(code),
+    }
+}
+
+/// Whether a device with the given capabilities looks like a joystick or gamepad.
+///
+/// That is the case if it has any `BTN_JOYSTICK` or `BTN_GAMEPAD` range button, or
+/// any absolute axis. Keyboards, mice and power buttons have neither.
+pub fn is_joystick(
+    axes: Option<&AttributeSetRef<AbsoluteAxisCode>>,
+    keys: Option<&AttributeSetRef<KeyCode>>,
+) -> bool {
+    // BTN_JOYSTICK (BTN_TRIGGER) up to the end of the BTN_GAMEPAD block
+    let joystick_buttons = KeyCode::BTN_TRIGGER.code()..KeyCode::BTN_TOOL_PEN.code();
+    let has_buttons = keys.is_some_and(|keys| {
+        keys.iter()
+            .any(|key| joystick_buttons.contains(&key.code()))
+    });
+    let has_axes = axes.is_some_and(|axes| axes.iter().next().is_some());
+    has_buttons || has_axes
+}
+
+/// Enumerates the input devices, keeping only joysticks unless `include_all` is set.
+fn enumerate_devices(
+    include_all: bool,
+) -> impl Iterator<Item = (std::path::PathBuf, evdev::Device)> {
+    evdev::enumerate().filter(move |(_, device)| {
+        include_all || is_joystick(device.supported_absolute_axes(), device.supported_keys())
+    })
+}
+
 /// Fetches information about connected input devices.
 ///
-/// Returns a vector of DeviceInfo structs containing the device path and name.
+/// Returns a vector of DeviceInfo structs containing the device path, name, unique ID,
+/// physical path and vendor/product IDs.
 /// Joystick names default to "Unknown" if they cannot be retrieved.
+/// Only devices that look like joysticks or gamepads are listed, see [`is_joystick`].
+///
+/// # Arguments
+/// * `include_all` - List every input device, including keyboards and mice
 ///
 /// # Returns
-/// A `Vec<DeviceInfo>` containing information about all connected devices.
-#[pyfunction]
-pub fn fetch_connected_joysticks() -> Vec<JoystickInfo> {
-    let devices = evdev::enumerate().collect::<Vec<_>>();
-    let mut device_list = Vec::new();
-
-    for (path, device) in devices {
-        let device_info = JoystickInfo {
-            path: path.to_string_lossy().to_string(),
-            name: device.name().unwrap_or("Unknown").to_string(),
-        };
-        device_list.push(device_info);
+/// A `Vec<DeviceInfo>` containing information about the connected devices.
+#[cfg_attr(feature = "python", pyfunction)]
+#[cfg_attr(feature = "python", pyo3(signature = (include_all = false)))]
+pub fn fetch_connected_joysticks(include_all: bool) -> Vec<JoystickInfo> {
+    enumerate_devices(include_all)
+        .map(|(path, device)| JoystickInfo::from_device(&path, &device))
+        .collect()
+}
+
+/// Fetches information about connected input devices, including their capabilities.
+///
+/// Like [`fetch_connected_joysticks`], but also reports how many axes and buttons each
+/// device has and whether it has hats or force feedback. The capabilities come from
+/// the enumeration itself, no `Joystick` is opened.
+///
+/// # Arguments
+/// * `include_all` - List every input device, including keyboards and mice
+///
+/// # Returns
+/// A `Vec<JoystickInfo>` with the capability fields filled in.
+#[cfg_attr(feature = "python", pyfunction)]
+#[cfg_attr(feature = "python", pyo3(signature = (include_all = false)))]
+pub fn fetch_connected_joysticks_detailed(include_all: bool) -> Vec<JoystickInfo> {
+    enumerate_devices(include_all)
+        .map(|(path, device)| {
+            JoystickInfo::from_device(&path, &device).with_capabilities(
+                device.supported_absolute_axes(),
+                device.supported_keys(),
+                device.supported_ff(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HAT0X: u16 = 16;
+    const HAT0Y: u16 = 17;
+
+    #[test]
+    fn test_hat_direction_all_directions() {
+        let directions = [
+            (0, 0),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ];
+
+        for (x, y) in directions {
+            let mut state = JoystickState::new();
+            state.hats.insert(HAT0X, x);
+            state.hats.insert(HAT0Y, y);
+            assert_eq!(state.hat_direction(HAT0X), Some((x, y)));
+        }
+    }
+
+    #[test]
+    fn test_input_key_with_partial_aliases() {
+        let description = crate::inner::description::DeviceDescription::from_toml_str_rust(
+            r#"
+device_name = "Stick"
+
+[[axes]]
+code = 0
+alias = "Roll"
+
+[[axes]]
+code = 1
+
+[[buttons]]
+code = 288
+alias = "Trigger"
+
+[[buttons]]
+code = 289
+"#,
+        )
+        .unwrap();
+        let aliases = description.aliases();
+
+        assert_eq!(
+            input_key(0, Some(&aliases.axes)),
+            InputKey::Alias("Roll".to_string())
+        );
+        assert_eq!(input_key(1, Some(&aliases.axes)), InputKey::Code(1));
+        assert_eq!(
+            input_key(288, Some(&aliases.buttons)),
+            InputKey::Alias("Trigger".to_string())
+        );
+        assert_eq!(input_key(289, Some(&aliases.buttons)), InputKey::Code(289));
+        // Aliases of one kind never leak into another
+        assert_eq!(input_key(288, Some(&aliases.axes)), InputKey::Code(288));
+        assert!(aliases.hats.is_empty());
+    }
+
+    #[test]
+    fn test_input_key_without_aliases() {
+        assert_eq!(input_key(0, None), InputKey::Code(0));
+        assert_eq!(input_key(288, None), InputKey::Code(288));
+    }
+
+    #[test]
+    fn test_hat_direction_missing_axes() {
+        let mut state = JoystickState::new();
+        assert_eq!(state.hat_direction(HAT0X), None);
+
+        state.hats.insert(HAT0Y, 1);
+        assert_eq!(state.hat_direction(HAT0X), Some((0, 1)));
+        assert_eq!(state.hat_direction(18), None);
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_changed_entries() {
+        let mut before = JoystickState::new();
+        before.axes.insert(0, 0.5);
+        before.axes.insert(1, -0.25);
+        before.buttons.insert(288, 1);
+        before.hats.insert(HAT0X, -1);
+
+        let mut after = JoystickState::new();
+        after.axes.insert(0, 0.75);
+        after.axes.insert(1, -0.25);
+        after.buttons.insert(289, 1);
+        after.hats.insert(HAT0X, -1);
+        after.hats.insert(HAT0Y, 1);
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.axes, HashMap::from([(0, 0.75)]));
+        assert_eq!(diff.buttons, HashMap::from([(288, 0), (289, 1)]));
+        assert_eq!(diff.hats, HashMap::from([(HAT0Y, 1)]));
+        assert!(diff.rel_axes.is_empty());
+
+        after.rel_axes.insert(8, 3);
+        assert_ne!(before, after);
+        assert_eq!(before.diff(&after).rel_axes, HashMap::from([(8, 3)]));
+        assert!(before.diff(&before).is_empty());
+    }
+
+    #[test]
+    fn test_joystick_info_capabilities() {
+        let mut axes = evdev::AttributeSet::<AbsoluteAxisCode>::new();
+        axes.insert(AbsoluteAxisCode::ABS_X);
+        axes.insert(AbsoluteAxisCode::ABS_Y);
+        axes.insert(AbsoluteAxisCode::ABS_THROTTLE);
+        axes.insert(AbsoluteAxisCode::ABS_HAT0X);
+        axes.insert(AbsoluteAxisCode::ABS_HAT0Y);
+        let mut keys = evdev::AttributeSet::<KeyCode>::new();
+        keys.insert(KeyCode::BTN_TRIGGER);
+        keys.insert(KeyCode::BTN_THUMB);
+        let mut ff = evdev::AttributeSet::<FFEffectCode>::new();
+        ff.insert(FFEffectCode::FF_RUMBLE);
+
+        let info = JoystickInfo::default().with_capabilities(Some(&axes), Some(&keys), Some(&ff));
+        assert_eq!(info.num_axes, 3);
+        assert_eq!(info.num_buttons, 2);
+        assert!(info.has_hats);
+        assert!(info.has_ff);
+
+        let info = JoystickInfo::default().with_capabilities(
+            None,
+            Some(&keys),
+            Some(&evdev::AttributeSet::new()),
+        );
+        assert_eq!(info.num_axes, 0);
+        assert!(!info.has_hats);
+        assert!(!info.has_ff);
+    }
+
+    #[test]
+    fn test_is_joystick_excludes_keyboards() {
+        let mut keyboard = evdev::AttributeSet::<KeyCode>::new();
+        keyboard.insert(KeyCode::KEY_A);
+        keyboard.insert(KeyCode::KEY_ENTER);
+        assert!(!is_joystick(None, Some(&keyboard)));
+        assert!(!is_joystick(None, None));
+
+        let mut axes = evdev::AttributeSet::<AbsoluteAxisCode>::new();
+        axes.insert(AbsoluteAxisCode::ABS_X);
+        assert!(is_joystick(Some(&axes), Some(&keyboard)));
+
+        let mut gamepad = evdev::AttributeSet::<KeyCode>::new();
+        gamepad.insert(KeyCode::BTN_SOUTH);
+        assert!(is_joystick(None, Some(&gamepad)));
     }
 
-    device_list
+    fn populated_state() -> JoystickState {
+        let mut state = JoystickState::new();
+        state.axes.insert(0, -0.5);
+        state.buttons.insert(288, 1);
+        state.hats.insert(HAT0X, -1);
+        state.raw_axes.insert(0, 256);
+        state.button_hold_ms.insert(288, 120);
+        state.pressed_this_cycle.insert(288, true);
+        state.rel_axes.insert(8, -3);
+        state.timestamp_us = 42;
+        state
+    }
+
+    fn assert_same_state(parsed: &JoystickState, state: &JoystickState) {
+        assert_eq!(parsed, state);
+        assert_eq!(parsed.raw_axes, state.raw_axes);
+        assert_eq!(parsed.button_hold_ms, state.button_hold_ms);
+        assert_eq!(parsed.pressed_this_cycle, state.pressed_this_cycle);
+        assert_eq!(parsed.timestamp_us, state.timestamp_us);
+    }
+
+    #[test]
+    fn test_state_json_round_trip() {
+        let state = populated_state();
+        let json = serde_json::to_string(&state).unwrap();
+        let parsed: JoystickState = serde_json::from_str(&json).unwrap();
+        assert_same_state(&parsed, &state);
+    }
+
+    #[test]
+    fn test_state_toml_round_trip() {
+        let state = populated_state();
+        let toml = toml::to_string(&state).unwrap();
+        let parsed: JoystickState = toml::from_str(&toml).unwrap();
+        assert_same_state(&parsed, &state);
+
+        let partial: JoystickState = toml::from_str("[axes]\n0 = 0.25").unwrap();
+        assert_eq!(partial.axes[&0], 0.25);
+        assert!(toml::from_str::<JoystickState>("[axes]\nx = 0.25").is_err());
+    }
+
+    #[test]
+    fn test_hat_as_direction_all_directions() {
+        let directions = [
+            ((0, 0), HatDirection::Center),
+            ((0, -1), HatDirection::Up),
+            ((1, -1), HatDirection::UpRight),
+            ((1, 0), HatDirection::Right),
+            ((1, 1), HatDirection::DownRight),
+            ((0, 1), HatDirection::Down),
+            ((-1, 1), HatDirection::DownLeft),
+            ((-1, 0), HatDirection::Left),
+            ((-1, -1), HatDirection::UpLeft),
+        ];
+
+        for ((x, y), direction) in directions {
+            let mut state = JoystickState::new();
+            state.hats.insert(HAT0X, x);
+            state.hats.insert(HAT0Y, y);
+            assert_eq!(state.hat_as_direction(HAT0X), direction);
+        }
+        assert_eq!(
+            JoystickState::new().hat_as_direction(HAT0X),
+            HatDirection::Center
+        );
+    }
+
+    #[test]
+    fn test_hat_directions_keyed_by_x_axis() {
+        let mut state = JoystickState::new();
+        state.hats.insert(HAT0Y, -1);
+        state.hats.insert(18, 1);
+        state.hats.insert(19, 1);
+        assert_eq!(
+            state.hat_directions(),
+            HashMap::from([(HAT0X, HatDirection::Up), (18, HatDirection::DownRight)])
+        );
+    }
+
+    #[test]
+    fn test_rescale_axis_maps_center_and_endpoints() {
+        let mut state = JoystickState::new();
+        state.axes.extend([(0, -1.0), (1, 0.0), (2, 1.0)]);
+
+        assert_eq!(state.rescale_axis(0, 1000.0, 2000.0), Some(1000.0));
+        assert_eq!(state.rescale_axis(1, 1000.0, 2000.0), Some(1500.0));
+        assert_eq!(state.rescale_axis(2, 1000.0, 2000.0), Some(2000.0));
+        // A reversed range flips the direction
+        assert_eq!(state.rescale_axis(2, 2000.0, 1000.0), Some(1000.0));
+        assert_eq!(state.rescale_axis(5, 1000.0, 2000.0), None);
+    }
 }
@@ -0,0 +1,44 @@
+use crate::inner::combo::ComboMatcher;
+use crate::utils::JoystickState;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Python handle of a combo matcher.
+///
+/// The matcher sits behind a mutex so the handle can be shared between Python threads.
+#[pyclass]
+pub struct PyComboMatcher {
+    matcher: Mutex<ComboMatcher>,
+}
+
+#[pymethods]
+impl PyComboMatcher {
+    #[new]
+    pub fn new() -> Self {
+        PyComboMatcher {
+            matcher: Mutex::new(ComboMatcher::new()),
+        }
+    }
+
+    pub fn add_combo(&self, name: &str, device_name: &str, button_codes: Vec<u16>) {
+        let mut matcher = self.matcher.lock().unwrap_or_else(|e| e.into_inner());
+        matcher.add_combo(name, device_name, button_codes);
+    }
+
+    pub fn check(&self, state_dict: HashMap<String, JoystickState>) -> Vec<String> {
+        let mut matcher = self.matcher.lock().unwrap_or_else(|e| e.into_inner());
+        matcher.check(&state_dict)
+    }
+
+    pub fn active(&self) -> Vec<String> {
+        let matcher = self.matcher.lock().unwrap_or_else(|e| e.into_inner());
+        matcher.active()
+    }
+}
+
+impl Default for PyComboMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,24 +1,251 @@
-use crate::inner::device_pool::DevicePool;
+use crate::inner::description::{DeviceDescription, InputAliases};
+use crate::inner::device_pool::{
+    ButtonCallback, DevicePool, DevicePoolError, DeviceReport, PoolStatus,
+};
+use crate::utils::JoystickState;
+use pyo3::exceptions::PyStopAsyncIteration;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use pyo3_async_runtimes::tokio::future_into_py;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::Mutex;
+use tokio::sync::{broadcast, Mutex};
 
 #[pyclass]
 pub struct PyDevicePool {
     inner: Arc<Mutex<DevicePool>>,
+    status: PoolStatus,
+    format: StateFormat,
+}
+
+/// How the fetch methods hand out the state of each device.
+#[derive(Debug, Clone)]
+enum StateFormat {
+    /// `JoystickState` objects.
+    Object,
+    /// Dicts keyed by input code, see `JoystickState::to_dict`.
+    Dict,
+    /// Dicts keyed by the input aliases of each device.
+    Aliased(Arc<HashMap<String, InputAliases>>),
+}
+
+impl StateFormat {
+    /// Picks the format for the `as_dict` and `use_aliases` options of a pool.
+    ///
+    /// Aliased dicts are selected by `aliases` alone, since aliases only exist as dict keys.
+    fn new(as_dict: bool, aliases: Option<HashMap<String, InputAliases>>) -> Self {
+        match aliases {
+            Some(aliases) => StateFormat::Aliased(Arc::new(aliases)),
+            None if as_dict => StateFormat::Dict,
+            None => StateFormat::Object,
+        }
+    }
+}
+
+/// How long a stream waits for a change before releasing the pool, so `stop()` can run.
+const STREAM_WAIT: Duration = Duration::from_millis(100);
+
+/// Async iterator over the changed states of a pool, created by `PyDevicePool::stream`.
+///
+/// Each step has the semantics of `fetch()` without a timeout. Iteration ends once the
+/// pool has been stopped.
+#[pyclass]
+pub struct PyStateStream {
+    inner: Arc<Mutex<DevicePool>>,
+    format: StateFormat,
+}
+
+#[pymethods]
+impl PyStateStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+        let format = self.format.clone();
+        future_into_py::<_, PyObject>(py, async move {
+            loop {
+                let pool = inner.lock().await;
+                if !pool.is_running() {
+                    return Err(PyStopAsyncIteration::new_err("Device pool stopped"));
+                }
+
+                // A timeout only means nothing changed yet, wait again without the lock
+                if let Ok(state_map) = pool.fetch(Some(STREAM_WAIT), None).await {
+                    if !pool.is_running() {
+                        return Err(PyStopAsyncIteration::new_err("Device pool stopped"));
+                    }
+                    return Python::with_gil(|py| states_to_py(py, state_map, &format));
+                }
+            }
+        })
+    }
+}
+
+/// Async iterator over the names of devices whose state changed, created by
+/// `PyDevicePool::subscribe`.
+///
+/// Iteration ends once the pool has been stopped. Notifications missed because the
+/// iterator fell too far behind are skipped. Only the running flag of the pool is
+/// checked, so waiting never blocks a `fetch()` holding the pool.
+#[pyclass]
+pub struct PyActivityStream {
+    running: Arc<std::sync::Mutex<bool>>,
+    activity: Arc<Mutex<broadcast::Receiver<String>>>,
+}
+
+#[pymethods]
+impl PyActivityStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let running = Arc::clone(&self.running);
+        let activity = Arc::clone(&self.activity);
+        future_into_py(py, async move {
+            let mut activity = activity.lock().await;
+            loop {
+                if !*running.lock().unwrap_or_else(|e| e.into_inner()) {
+                    return Err(PyStopAsyncIteration::new_err("Device pool stopped"));
+                }
+                // A timeout only means no activity yet, check again whether to stop
+                match tokio::time::timeout(STREAM_WAIT, activity.recv()).await {
+                    Ok(Ok(device_name)) => return Ok(device_name),
+                    Ok(Err(broadcast::error::RecvError::Lagged(_))) | Err(_) => {}
+                    Ok(Err(broadcast::error::RecvError::Closed)) => {
+                        return Err(PyStopAsyncIteration::new_err("Device pool dropped"));
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Runs `future` to completion for the synchronous methods.
+///
+/// Outside of a tokio runtime this uses the global runtime of `pyo3_async_runtimes`.
+/// Blocking on a runtime from a runtime thread panics, so when called from inside one,
+/// e.g. with Python embedded in a Rust application, a multi-threaded runtime runs the
+/// future itself and a current-thread runtime hands it to a helper thread.
+fn block_on<F>(future: F) -> F::Output
+where
+    F: std::future::Future + Send,
+    F::Output: Send,
+{
+    let global = pyo3_async_runtimes::tokio::get_runtime;
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| handle.block_on(future))
+        }
+        Ok(_) => std::thread::scope(|scope| {
+            scope
+                .spawn(|| global().block_on(future))
+                .join()
+                .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+        }),
+        Err(_) => global().block_on(future),
+    }
+}
+
+/// Maps a pool error onto the matching Python exception.
+///
+/// Timeouts raise `asyncio.TimeoutError`, so `except asyncio.TimeoutError` works for
+/// `fetch` just like for `asyncio.wait_for`.
+pub(crate) fn pool_error_to_py(error: DevicePoolError) -> PyErr {
+    let message = error.to_string();
+    match error {
+        DevicePoolError::NotRunning => PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(message),
+        DevicePoolError::Timeout | DevicePoolError::MissingDevices(_) => Python::with_gil(|py| {
+            match py
+                .import("asyncio")
+                .and_then(|asyncio| asyncio.getattr("TimeoutError"))
+                .and_then(|timeout| Ok(timeout.downcast_into::<pyo3::types::PyType>()?))
+            {
+                Ok(timeout) => PyErr::from_type(timeout, message),
+                Err(_) => PyErr::new::<pyo3::exceptions::PyTimeoutError, _>(message),
+            }
+        }),
+        DevicePoolError::UnknownDevice(_) => PyErr::new::<pyo3::exceptions::PyKeyError, _>(message),
+        DevicePoolError::Io(_) => PyErr::new::<pyo3::exceptions::PyIOError, _>(message),
+    }
+}
+
+/// Converts fetched states into the Python dict handed out by the fetch methods.
+///
+/// The values are `JoystickState` objects or dicts, as selected by `format`.
+fn states_to_py(
+    py: Python,
+    state_map: HashMap<String, JoystickState>,
+    format: &StateFormat,
+) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    for (device_name, state) in state_map {
+        let state = state_to_py(py, &device_name, state, format)?;
+        dict.set_item(device_name, state)?;
+    }
+    Ok(dict.into())
+}
+
+/// Converts the fetched state of `device_name` into a `JoystickState` object or a dict.
+fn state_to_py(
+    py: Python,
+    device_name: &str,
+    state: JoystickState,
+    format: &StateFormat,
+) -> PyResult<PyObject> {
+    match format {
+        StateFormat::Object => Ok(state.into_pyobject(py)?.into_any().unbind()),
+        StateFormat::Dict => state.to_dict(py),
+        StateFormat::Aliased(aliases) => state.to_dict_keyed(py, aliases.get(device_name)),
+    }
+}
+
+impl PyDevicePool {
+    /// Returns the pool shared by this handle, for wrappers driven by a pool.
+    pub(crate) fn shared_pool(&self) -> Arc<Mutex<DevicePool>> {
+        Arc::clone(&self.inner)
+    }
 }
 
 #[pymethods]
 impl PyDevicePool {
     #[new]
-    #[pyo3(signature = (device_desc_files = Vec::new(), debounce_seconds = 0.1))]
-    fn new(device_desc_files: Vec<String>, debounce_seconds: f64) -> Self {
-        let pool = DevicePool::new(device_desc_files, debounce_seconds);
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (device_desc_files = Vec::new(), debounce_seconds = 0.1, descriptions = Vec::new(), hotplug = false, use_aliases = false, as_dict = false, max_open_retries = 0, open_retry_delay_seconds = 0.1, change_epsilon = 0.0))]
+    fn new(
+        device_desc_files: Vec<String>,
+        debounce_seconds: f64,
+        descriptions: Vec<DeviceDescription>,
+        hotplug: bool,
+        use_aliases: bool,
+        as_dict: bool,
+        max_open_retries: u32,
+        open_retry_delay_seconds: f64,
+        change_epsilon: f32,
+    ) -> Self {
+        let (mut all_descriptions, load_errors) =
+            DevicePool::try_load_descriptions(&device_desc_files);
+        all_descriptions.extend(descriptions);
+        let aliases = use_aliases.then(|| {
+            all_descriptions
+                .iter()
+                .map(|desc| (desc.device_name.clone(), desc.aliases()))
+                .collect()
+        });
+        let mut pool = DevicePool::from_descriptions(all_descriptions, debounce_seconds, hotplug);
+        pool.set_load_errors(load_errors);
+        pool.set_open_retries(
+            max_open_retries,
+            Duration::from_secs_f64(open_retry_delay_seconds),
+        );
+        pool.set_change_epsilon(change_epsilon);
         Self {
+            status: pool.status(),
             inner: Arc::new(Mutex::new(pool)),
+            format: StateFormat::new(as_dict, aliases),
         }
     }
 
@@ -31,54 +258,280 @@ impl PyDevicePool {
         })
     }
 
+    fn start<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let mut pool = inner.lock().await;
+            let connected_devices = pool.start().await;
+            Ok(connected_devices)
+        })
+    }
+
+    fn reset_preserve_axes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let mut pool = inner.lock().await;
+            let connected_devices = pool.reset_preserve_axes().await;
+            Ok(connected_devices)
+        })
+    }
+
     fn fetch_nowait(&self, py: Python) -> PyResult<PyObject> {
         let inner = Arc::clone(&self.inner);
 
-        pyo3_async_runtimes::tokio::get_runtime().block_on(async {
+        let fetched = block_on(async {
             let pool = inner.lock().await;
-            match pool.fetch_nowait() {
-                Ok(state_map) => {
-                    let dict = PyDict::new(py);
-                    for (device_name, state) in state_map {
-                        dict.set_item(device_name, state)?;
-                    }
-                    Ok(dict.into())
-                }
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e)),
+            pool.fetch_nowait()
+        });
+        match fetched {
+            Ok(state_map) => states_to_py(py, state_map, &self.format),
+            Err(e) => Err(pool_error_to_py(e)),
+        }
+    }
+
+    fn fetch_nowait_changed(&self, py: Python) -> PyResult<Option<PyObject>> {
+        let inner = Arc::clone(&self.inner);
+
+        let fetched = block_on(async {
+            let pool = inner.lock().await;
+            pool.fetch_nowait_changed()
+        });
+        match fetched {
+            Ok(Some(state_map)) => states_to_py(py, state_map, &self.format).map(Some),
+            Ok(None) => Ok(None),
+            Err(e) => Err(pool_error_to_py(e)),
+        }
+    }
+
+    fn fetch_last(&self, py: Python) -> PyResult<PyObject> {
+        let inner = Arc::clone(&self.inner);
+
+        let state_map = block_on(async {
+            let pool = inner.lock().await;
+            pool.fetch_last()
+        });
+        states_to_py(py, state_map, &self.format)
+    }
+
+    #[pyo3(signature = (timeout_seconds = None, device_filter = None, return_on_timeout = false))]
+    fn fetch<'py>(
+        &self,
+        py: Python<'py>,
+        timeout_seconds: Option<f64>,
+        device_filter: Option<Vec<String>>,
+        return_on_timeout: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+        let format = self.format.clone();
+        future_into_py::<_, PyObject>(py, async move {
+            let pool = inner.lock().await;
+            let timeout_duration = timeout_seconds.map(Duration::from_secs_f64);
+
+            let result = if return_on_timeout {
+                pool.fetch_or_current(timeout_duration, device_filter).await
+            } else {
+                pool.fetch(timeout_duration, device_filter).await
+            };
+            match result {
+                Ok(state_map) => Python::with_gil(|py| states_to_py(py, state_map, &format)),
+                Err(e) => Err(pool_error_to_py(e)),
             }
         })
     }
 
-    #[pyo3(signature = (timeout_seconds = None))]
-    fn fetch<'py>(
+    #[pyo3(signature = (device_name, timeout_seconds = None))]
+    fn fetch_device<'py>(
         &self,
         py: Python<'py>,
+        device_name: String,
         timeout_seconds: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let inner = Arc::clone(&self.inner);
+        let format = self.format.clone();
         future_into_py::<_, PyObject>(py, async move {
             let pool = inner.lock().await;
             let timeout_duration = timeout_seconds.map(Duration::from_secs_f64);
 
-            match pool.fetch(timeout_duration).await {
-                Ok(state_map) => Python::with_gil(|py| {
-                    let dict = PyDict::new(py);
-                    for (device_name, state) in state_map {
-                        dict.set_item(device_name, state)?;
-                    }
-                    Ok(dict.into())
-                }),
-                Err(e) => Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e)),
+            match pool.fetch_device(&device_name, timeout_duration).await {
+                Ok(state) => Python::with_gil(|py| state_to_py(py, &device_name, state, &format)),
+                Err(e) => Err(pool_error_to_py(e)),
             }
         })
     }
 
-    fn stop<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+    #[pyo3(signature = (timeout_seconds = None))]
+    fn events<'py>(
+        &self,
+        py: Python<'py>,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
         let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let pool = inner.lock().await;
+            let timeout_duration = timeout_seconds.map(Duration::from_secs_f64);
+
+            Ok(pool.events(timeout_duration).await)
+        })
+    }
+
+    fn is_running(&self) -> bool {
+        self.status.is_running()
+    }
+
+    fn pause(&self) {
+        self.status.pause()
+    }
+
+    fn resume(&self) {
+        self.status.resume()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.status.is_paused()
+    }
+
+    fn device_health(&self) -> HashMap<String, bool> {
+        self.status.device_health()
+    }
+
+    fn monitored_devices(&self) -> Vec<String> {
+        self.status.monitored_devices()
+    }
+
+    fn load_errors(&self) -> Vec<(String, String)> {
+        let inner = Arc::clone(&self.inner);
+
+        block_on(async {
+            let pool = inner.lock().await;
+            pool.load_errors()
+        })
+    }
+
+    fn device_report(&self) -> Vec<DeviceReport> {
+        let inner = Arc::clone(&self.inner);
+
+        block_on(async {
+            let pool = inner.lock().await;
+            pool.device_report()
+        })
+    }
+
+    fn validate_against_hardware(&self) -> HashMap<String, Vec<u16>> {
+        let inner = Arc::clone(&self.inner);
+
+        block_on(async {
+            let pool = inner.lock().await;
+            pool.validate_against_hardware()
+        })
+    }
+
+    fn last_errors(&self) -> HashMap<String, String> {
+        let inner = Arc::clone(&self.inner);
+
+        block_on(async {
+            let pool = inner.lock().await;
+            pool.last_errors()
+        })
+    }
+
+    fn debounced_codes(&self) -> Vec<(String, u16)> {
+        let inner = Arc::clone(&self.inner);
+
+        block_on(async {
+            let pool = inner.lock().await;
+            pool.debounced_codes()
+        })
+    }
+
+    #[pyo3(signature = (timeout_seconds = None))]
+    fn wait_for_all_devices<'py>(
+        &self,
+        py: Python<'py>,
+        timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let pool = inner.lock().await;
+            let timeout_duration = timeout_seconds.map(Duration::from_secs_f64);
+
+            pool.wait_for_all_devices(timeout_duration)
+                .await
+                .map_err(pool_error_to_py)
+        })
+    }
+
+    fn on_button(&self, device_name: &str, code: u16, callback: PyObject) {
+        let callback: ButtonCallback = Arc::new(move |device_name: &str, code: u16| {
+            Python::with_gil(|py| {
+                if let Err(e) = callback.call1(py, (device_name, code)) {
+                    let traceback = e
+                        .traceback(py)
+                        .and_then(|traceback| traceback.format().ok())
+                        .unwrap_or_default();
+                    log::error!(
+                        "Button callback for {} {} raised: {}\n{}",
+                        device_name,
+                        code,
+                        e,
+                        traceback.trim_end()
+                    );
+                }
+            })
+        });
+        let inner = Arc::clone(&self.inner);
+
+        block_on(async {
+            let pool = inner.lock().await;
+            pool.on_button(device_name, code, callback);
+        })
+    }
+
+    fn stream(&self) -> PyStateStream {
+        PyStateStream {
+            inner: Arc::clone(&self.inner),
+            format: self.format.clone(),
+        }
+    }
+
+    fn subscribe(&self) -> PyActivityStream {
+        let inner = Arc::clone(&self.inner);
+        let (running, activity) = block_on(async {
+            let pool = inner.lock().await;
+            (pool.running_flag(), pool.subscribe())
+        });
+        PyActivityStream {
+            running,
+            activity: Arc::new(Mutex::new(activity)),
+        }
+    }
+
+    #[pyo3(signature = (drain_timeout_seconds = None))]
+    fn stop<'py>(
+        &self,
+        py: Python<'py>,
+        drain_timeout_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+        let drain_timeout = drain_timeout_seconds.map(Duration::from_secs_f64);
         future_into_py(py, async move {
             let mut pool = inner.lock().await;
-            pool.stop().await;
+            pool.stop(drain_timeout).await;
             Ok(())
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_format_defaults_to_objects() {
+        assert!(matches!(StateFormat::new(false, None), StateFormat::Object));
+        assert!(matches!(StateFormat::new(true, None), StateFormat::Dict));
+        assert!(matches!(
+            StateFormat::new(false, Some(HashMap::new())),
+            StateFormat::Aliased(_)
+        ));
+    }
+}
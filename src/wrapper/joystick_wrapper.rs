@@ -1,21 +1,83 @@
-use crate::{inner::joystick::Joystick, utils::JoystickState};
+use crate::{
+    inner::{description::DeviceDescription, joystick::Joystick},
+    utils::{fetch_connected_joysticks, JoystickState},
+};
 use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
 
+/// Python handle of a single joystick.
+///
+/// The joystick sits behind a mutex so the handle can be shared between Python
+/// threads: concurrent calls are serialized and each one has exclusive access to
+/// the evdev device while it reads or uploads effects.
 #[pyclass]
 pub struct PyJoystick {
-    joystick: Joystick,
+    joystick: Arc<Mutex<Joystick>>,
+}
+
+impl PyJoystick {
+    /// Runs `f` with exclusive access to the joystick.
+    fn with_joystick<R>(&self, f: impl FnOnce(&mut Joystick) -> R) -> R {
+        let mut joystick = self.joystick.lock().unwrap_or_else(|e| e.into_inner());
+        f(&mut joystick)
+    }
+
+    /// Opens the device at `device_path`, see `new`.
+    fn open(
+        device_path: &str,
+        description: Option<DeviceDescription>,
+        max_events_per_poll: Option<usize>,
+    ) -> PyResult<Self> {
+        let max_events_per_poll = max_events_per_poll
+            .map(|max| {
+                NonZeroUsize::new(max).ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "max_events_per_poll must be at least 1",
+                    )
+                })
+            })
+            .transpose()?;
+        let mut joystick = match description {
+            Some(description) => Joystick::with_description(device_path, &description)?,
+            None => Joystick::new(device_path)?,
+        };
+        joystick.set_max_events_per_poll(max_events_per_poll);
+        Ok(PyJoystick {
+            joystick: Arc::new(Mutex::new(joystick)),
+        })
+    }
 }
 
 #[pymethods]
 impl PyJoystick {
     #[new]
-    pub fn new(device_path: &str) -> PyResult<Self> {
-        let joystick = Joystick::new(device_path)?;
-        Ok(PyJoystick { joystick })
+    #[pyo3(signature = (device_path, description = None, max_events_per_poll = None))]
+    pub fn new(
+        device_path: &str,
+        description: Option<DeviceDescription>,
+        max_events_per_poll: Option<usize>,
+    ) -> PyResult<Self> {
+        Self::open(device_path, description, max_events_per_poll)
     }
 
-    pub fn get_state(&mut self) -> PyResult<JoystickState> {
-        match self.joystick.get_state() {
+    /// Opens the first connected joystick named `name`, raising `FileNotFoundError`
+    /// if there is none.
+    #[staticmethod]
+    #[pyo3(signature = (name, description = None, max_events_per_poll = None))]
+    pub fn by_name(
+        name: &str,
+        description: Option<DeviceDescription>,
+        max_events_per_poll: Option<usize>,
+    ) -> PyResult<Self> {
+        Joystick::open_by_name(name, &fetch_connected_joysticks(false), |path| {
+            Self::open(path, description, max_events_per_poll)
+        })
+    }
+
+    pub fn get_state(&self, py: Python) -> PyResult<JoystickState> {
+        match py.allow_threads(|| self.with_joystick(|joystick| joystick.get_state())) {
             Ok(state) => Ok(state),
             Err(e) => Err(PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
                 "Failed to get joystick state: {}",
@@ -23,4 +85,67 @@ impl PyJoystick {
             ))),
         }
     }
+
+    pub fn get_state_delta(&self, py: Python) -> PyResult<JoystickState> {
+        py.allow_threads(|| self.with_joystick(|joystick| joystick.get_state_delta()))
+            .map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+                    "Failed to get joystick state: {}",
+                    e
+                ))
+            })
+    }
+
+    pub fn get_state_full(&self, py: Python) -> PyResult<JoystickState> {
+        py.allow_threads(|| self.with_joystick(|joystick| joystick.get_state_full()))
+            .map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+                    "Failed to get joystick state: {}",
+                    e
+                ))
+            })
+    }
+
+    pub fn rumble(&self, py: Python, strong: u16, weak: u16, duration_ms: u32) -> PyResult<()> {
+        py.allow_threads(|| {
+            self.with_joystick(|joystick| joystick.rumble(strong, weak, duration_ms))
+        })
+        .map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("Failed to rumble: {}", e))
+        })
+    }
+
+    pub fn set_grab(&self, grab: bool) -> PyResult<()> {
+        self.with_joystick(|joystick| joystick.set_grab(grab))
+            .map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+                    "Failed to set joystick grab: {}",
+                    e
+                ))
+            })
+    }
+
+    #[getter]
+    pub fn grabbed(&self) -> bool {
+        self.with_joystick(|joystick| joystick.is_grabbed())
+    }
+
+    pub fn axis_ranges(&self) -> HashMap<u16, (i32, i32)> {
+        self.with_joystick(|joystick| joystick.layout().axis_ranges())
+    }
+
+    pub fn capabilities(&self) -> HashMap<String, Vec<u16>> {
+        self.with_joystick(|joystick| joystick.layout().capabilities())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_py_joystick_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<PyJoystick>();
+    }
 }
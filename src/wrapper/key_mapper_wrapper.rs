@@ -0,0 +1,67 @@
+use crate::inner::device_pool::DevicePoolError;
+use crate::inner::key_mapper::{KeyMapper, KEY_POLL};
+use crate::wrapper::device_pool_wrapper::{pool_error_to_py, PyDevicePool};
+use evdev::KeyCode;
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::future_into_py;
+use std::sync::{Arc, Mutex};
+
+/// Python handle of a key mapper.
+///
+/// `run` only holds the pool for one fetch at a time, so the pool stays usable (and
+/// stoppable) while the mapper is running.
+#[pyclass]
+pub struct PyKeyMapper {
+    mapper: Arc<Mutex<KeyMapper>>,
+}
+
+#[pymethods]
+impl PyKeyMapper {
+    #[new]
+    pub fn new() -> Self {
+        PyKeyMapper {
+            mapper: Arc::new(Mutex::new(KeyMapper::new())),
+        }
+    }
+
+    pub fn add_binding(&self, device_name: &str, button_code: u16, key_code: u16) {
+        let mut mapper = self.mapper.lock().unwrap_or_else(|e| e.into_inner());
+        mapper.add_binding(device_name, button_code, KeyCode::new(key_code));
+    }
+
+    pub fn run<'py>(&self, py: Python<'py>, pool: &PyDevicePool) -> PyResult<Bound<'py, PyAny>> {
+        let inner = pool.shared_pool();
+        let mapper = Arc::clone(&self.mapper);
+        future_into_py(py, async move {
+            let mut started = false;
+            loop {
+                let pool = inner.lock().await;
+                if !pool.is_running() {
+                    break;
+                }
+                started = true;
+                let states = pool
+                    .fetch_or_current(Some(KEY_POLL), None)
+                    .await
+                    .map_err(pool_error_to_py)?;
+                let mut mapper = mapper.lock().unwrap_or_else(|e| e.into_inner());
+                mapper
+                    .apply(&states, &pool)
+                    .map_err(|e| pool_error_to_py(DevicePoolError::Io(e)))?;
+            }
+            if !started {
+                return Err(pool_error_to_py(DevicePoolError::NotRunning));
+            }
+            let mut mapper = mapper.lock().unwrap_or_else(|e| e.into_inner());
+            mapper
+                .release_all()
+                .map_err(|e| pool_error_to_py(DevicePoolError::Io(e)))
+        })
+    }
+}
+
+impl Default for PyKeyMapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
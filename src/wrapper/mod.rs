@@ -1,2 +1,5 @@
+pub mod combo_wrapper;
 pub mod device_pool_wrapper;
 pub mod joystick_wrapper;
+pub mod key_mapper_wrapper;
+pub mod virtual_joystick_wrapper;
@@ -0,0 +1,44 @@
+use crate::{
+    inner::{description::DeviceDescription, virtual_joystick::VirtualJoystick},
+    utils::JoystickState,
+};
+use pyo3::prelude::*;
+use std::sync::Mutex;
+
+/// Python handle of a virtual joystick.
+///
+/// Like `PyJoystick`, the device sits behind a mutex so the handle can be shared
+/// between Python threads.
+#[pyclass]
+pub struct PyVirtualJoystick {
+    joystick: Mutex<VirtualJoystick>,
+}
+
+#[pymethods]
+impl PyVirtualJoystick {
+    #[new]
+    pub fn new(description: DeviceDescription) -> PyResult<Self> {
+        let joystick = VirtualJoystick::new(&description).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+                "Failed to create virtual joystick: {}",
+                e
+            ))
+        })?;
+        Ok(PyVirtualJoystick {
+            joystick: Mutex::new(joystick),
+        })
+    }
+
+    pub fn emit(&self, py: Python, state: JoystickState) -> PyResult<()> {
+        py.allow_threads(|| {
+            let mut joystick = self.joystick.lock().unwrap_or_else(|e| e.into_inner());
+            joystick.emit(&state)
+        })
+        .map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+                "Failed to emit virtual joystick state: {}",
+                e
+            ))
+        })
+    }
+}